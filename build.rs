@@ -0,0 +1,50 @@
+/// Generates the registry of solutions that is linked into the `multi` binary.
+/// Every scaffolded `src/bin/YYYY-DD.rs` is included as a module and its `SOLUTION` is collected into `SOLUTIONS`.
+use std::{env, fs, path::Path};
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    println!("cargo:rerun-if-changed={}", bin_dir.display());
+
+    let mut days: Vec<(u16, u8)> = fs::read_dir(&bin_dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != "rs" {
+                return None;
+            }
+            let stem = path.file_stem()?.to_str()?;
+            let (year, day) = stem.split_once('-')?;
+            if year.len() != 4 || day.len() != 2 {
+                return None;
+            }
+            let (year, day) = (year.parse().ok()?, day.parse().ok()?);
+            (year >= 2015 && (1..=25).contains(&day)).then_some((year, day))
+        })
+        .collect();
+
+    days.sort_unstable();
+
+    let mut modules = String::new();
+    let mut solutions = String::new();
+
+    for (year, day) in days {
+        let path = bin_dir.join(format!("{year}-{day:02}.rs"));
+        let module = format!("year_{year}_day_{day:02}");
+        // the examples test runs without the libtest harness, so the tests of a solution are dropped,
+        // but the imports of its `tests` module remain.
+        modules.push_str(&format!(
+            "#[allow(dead_code, unused_imports)]\n#[path = {path:?}]\nmod {module};\n"
+        ));
+        solutions.push_str(&format!("    {module}::SOLUTION,\n"));
+    }
+
+    let registry = format!(
+        "{modules}\npub const SOLUTIONS: &[advent_of_code::template::Solution] = &[\n{solutions}];\n"
+    );
+
+    fs::write(Path::new(&out_dir).join("solutions.rs"), registry).unwrap();
+}
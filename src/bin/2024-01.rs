@@ -0,0 +1,61 @@
+use advent_of_code::parse::{parse_lines, parse_value, split_once};
+use advent_of_code::ParseError;
+use itertools::Itertools;
+
+advent_of_code::solution!(2024, 1);
+
+pub fn part_one(input: &str) -> Result<i32, ParseError> {
+    let (mut lefts, mut rights): (Vec<_>, Vec<_>) = parse_lines::<LocationPair>(input)?
+        .into_iter()
+        .map(|x| (x.0, x.1))
+        .unzip();
+
+    lefts.sort_unstable();
+    rights.sort_unstable();
+
+    Ok(lefts
+        .into_iter()
+        .zip(rights)
+        .map(|(l, r)| (l - r).abs())
+        .sum())
+}
+
+pub fn part_two(input: &str) -> Result<i32, ParseError> {
+    let pairs: Vec<LocationPair> = parse_lines(input)?;
+
+    let counts = pairs.iter().map(|pair| pair.1).counts();
+
+    Ok(pairs
+        .iter()
+        .map(|pair| pair.0)
+        .map(|i| i * counts.get(&i).copied().unwrap_or_default() as i32)
+        .sum())
+}
+
+struct LocationPair(i32, i32);
+
+impl TryFrom<&str> for LocationPair {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let (left, right) = split_once(line.trim(), " ")?;
+        Ok(Self(parse_value(line, left)?, parse_value(line, right)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Ok(11));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Ok(31));
+    }
+}
@@ -1,7 +1,7 @@
 use regex::Regex;
 use std::sync::LazyLock;
 
-advent_of_code::solution!(3);
+advent_of_code::solution!(2024, 3);
 
 static MUL_RE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap());
@@ -34,13 +34,14 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(161));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let input = advent_of_code::template::read_file_part("examples", YEAR, DAY, 2);
+        let result = part_two(&input);
+        assert_eq!(result, Some(48));
     }
 }
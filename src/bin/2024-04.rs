@@ -4,7 +4,7 @@ use advent_of_code::{columns, major_diagonals, minor_diagonals, rows};
 use fancy_regex::Regex;
 use itertools::iproduct;
 
-advent_of_code::solution!(4);
+advent_of_code::solution!(2024, 4);
 
 static XMAS_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?=(XMAS|SAMX))").unwrap());
 
@@ -49,13 +49,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(18));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(9));
     }
 }
@@ -0,0 +1,119 @@
+use advent_of_code::parse::{parse_lines, parse_value, split_once};
+use advent_of_code::ParseError;
+
+advent_of_code::solution!(2024, 5);
+
+pub fn part_one(input: &str) -> Result<i32, ParseError> {
+    let (rules, updates) = parse(input)?;
+
+    Ok(updates
+        .iter()
+        .filter(|update| rules.iter().all(|rule| !update.violates(rule)))
+        .map(|update| update[update.len() / 2])
+        .sum())
+}
+
+pub fn part_two(input: &str) -> Result<i32, ParseError> {
+    let (rules, updates) = parse(input)?;
+
+    Ok(updates
+        .into_iter()
+        .filter(|update| rules.iter().any(|rule| update.violates(rule)))
+        .map(|mut update| {
+            while rules.iter().any(|rule| update.violates(rule)) {
+                for rule in &rules {
+                    rule.apply(&mut update);
+                }
+            }
+            update[update.len() / 2]
+        })
+        .sum())
+}
+
+fn parse(input: &str) -> Result<(Vec<OrderingRule>, Vec<Vec<i32>>), ParseError> {
+    let input = input.replace("\r\n", "\n");
+    let (rules_part, updates_part) = input.split_once("\n\n").ok_or_else(|| {
+        ParseError::new("expected an empty line between the rules and the updates.")
+    })?;
+
+    let rules: Vec<OrderingRule> = parse_lines(rules_part)?;
+
+    // the updates start after the rules and the empty line.
+    let first_update = rules.len() + 2;
+    let updates = updates_part
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            line.split(',')
+                .map(|page| parse_value(line, page))
+                .collect::<Result<_, _>>()
+                .map_err(|e| e.on_line(first_update + i))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((rules, updates))
+}
+
+trait Violate<T> {
+    fn violates(&self, other: &T) -> bool;
+}
+
+impl Violate<OrderingRule> for Vec<i32> {
+    fn violates(&self, rule: &OrderingRule) -> bool {
+        if let Some((l, r)) = rule.find_pages(self) {
+            l > r
+        } else {
+            false
+        }
+    }
+}
+
+struct OrderingRule {
+    left: i32,
+    right: i32,
+}
+
+impl OrderingRule {
+    fn find_pages(&self, update: &Vec<i32>) -> Option<(usize, usize)> {
+        let left_pos = update.iter().position(|&x| x == self.left)?;
+        let right_pos = update.iter().position(|&x| x == self.right)?;
+        Some((left_pos, right_pos))
+    }
+
+    fn apply(&self, update: &mut Vec<i32>) {
+        if let Some((l, r)) = self.find_pages(update) {
+            if l > r {
+                update.swap(l, r);
+            }
+        }
+    }
+}
+
+impl TryFrom<&str> for OrderingRule {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let (left, right) = split_once(line, "|")?;
+        Ok(Self {
+            left: parse_value(line, left)?,
+            right: parse_value(line, right)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Ok(143));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Ok(123));
+    }
+}
@@ -2,15 +2,18 @@ use advent_of_code::indexed_chars;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use std::collections::HashSet;
 
-advent_of_code::solution!(6);
+advent_of_code::solution!(2024, 6, state);
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let visited = try_solve(input, None)?;
-    Some(visited.len())
+/// The tiles visited by the guard without an extra obstacle.
+type Visited = Option<HashSet<(i32, i32)>>;
+
+pub fn part_one(input: &str) -> (Option<usize>, Visited) {
+    let visited = try_solve(input, None);
+    (visited.as_ref().map(HashSet::len), visited)
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let visited = try_solve(input, None)?;
+pub fn part_two(input: &str, visited: &Visited) -> Option<usize> {
+    let visited = visited.as_ref()?;
 
     let blocked_paths: Vec<_> = visited
         .par_iter()
@@ -68,13 +71,14 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::template::read_file("examples", DAY);
-        assert_eq!(part_one(&input), Some(41));
+        let input = advent_of_code::template::read_file("examples", YEAR, DAY);
+        assert_eq!(part_one(&input).0, Some(41));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::template::read_file("examples", DAY);
-        assert_eq!(part_two(&input), Some(6));
+        let input = advent_of_code::template::read_file("examples", YEAR, DAY);
+        let (_, visited) = part_one(&input);
+        assert_eq!(part_two(&input, &visited), Some(6));
     }
 }
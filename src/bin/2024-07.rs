@@ -1,29 +1,26 @@
-use regex::Regex;
-use std::{
-    ops::{Add, Mul},
-    sync::LazyLock,
-};
+use advent_of_code::parse::{parse_lines, parse_value, split_once};
+use advent_of_code::ParseError;
+use std::ops::{Add, Mul};
 
-advent_of_code::solution!(7);
+advent_of_code::solution!(2024, 7);
 
-static EQUATION_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(\d+)").unwrap());
-
-pub fn part_one(input: &str) -> Option<i128> {
-    Some(solve(input, &[Add::add, Mul::mul]))
+pub fn part_one(input: &str) -> Result<i128, ParseError> {
+    solve(input, &[Add::add, Mul::mul])
 }
 
-pub fn part_two(input: &str) -> Option<i128> {
+pub fn part_two(input: &str) -> Result<i128, ParseError> {
     let concat = |l, r| format!("{}{}", l, r).parse().unwrap();
-    Some(solve(input, &[Add::add, Mul::mul, concat]))
+    solve(input, &[Add::add, Mul::mul, concat])
 }
 
-fn solve(input: &str, operations: &[fn(i128, i128) -> i128]) -> i128 {
-    input
-        .lines()
-        .map(Equation::from)
+fn solve(input: &str, operations: &[fn(i128, i128) -> i128]) -> Result<i128, ParseError> {
+    let equations: Vec<Equation> = parse_lines(input)?;
+
+    Ok(equations
+        .iter()
         .filter(|e| e.is_solvable(operations))
         .map(|e| e.target)
-        .sum()
+        .sum())
 }
 
 struct Equation {
@@ -58,15 +55,29 @@ impl Equation {
     }
 }
 
-impl From<&str> for Equation {
-    fn from(value: &str) -> Self {
-        let mut nums = EQUATION_RE
-            .find_iter(value)
-            .map(|m| m.as_str().parse().unwrap());
-        Self {
-            target: nums.next().unwrap(),
-            nums: nums.collect(),
+impl TryFrom<&str> for Equation {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let (target, nums) = split_once(line, ":")?;
+
+        let nums: Box<[i128]> = nums
+            .split_whitespace()
+            .map(|num| parse_value(line, num))
+            .collect::<Result<_, _>>()?;
+
+        if nums.is_empty() {
+            return Err(ParseError::at(
+                line,
+                &line[line.len()..],
+                "expected a number.",
+            ));
         }
+
+        Ok(Self {
+            target: parse_value(line, target)?,
+            nums,
+        })
     }
 }
 
@@ -76,13 +87,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3749));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Ok(3749));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Ok(11387));
     }
 }
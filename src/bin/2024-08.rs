@@ -3,7 +3,7 @@ use std::collections::{HashMap, HashSet};
 use advent_of_code::{bounds, indexed_chars};
 use itertools::Itertools;
 
-advent_of_code::solution!(8);
+advent_of_code::solution!(2024, 8);
 
 pub fn part_one(input: &str) -> Option<usize> {
     Some(solve(input, get_antinodes))
@@ -80,13 +80,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(14));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(34));
     }
 }
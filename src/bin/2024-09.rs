@@ -1,6 +1,6 @@
 use std::iter::repeat;
 
-advent_of_code::solution!(9);
+advent_of_code::solution!(2024, 9);
 
 pub fn part_one(input: &str) -> Option<usize> {
     Some(solve(input, compact_one))
@@ -126,13 +126,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(1928));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(2858));
     }
 }
@@ -2,7 +2,7 @@ use std::collections::{HashMap, HashSet};
 
 use advent_of_code::{indexed_chars_iter, DIRECTIONS};
 
-advent_of_code::solution!(10);
+advent_of_code::solution!(2024, 10);
 
 
 pub fn part_one(input: &str) -> Option<usize> {
@@ -54,13 +54,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(36));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(81));
     }
 }
@@ -1,4 +1,4 @@
-advent_of_code::solution!(13);
+advent_of_code::solution!(2024, 13);
 
 use advent_of_code::NUM_RE;
 use z3::{
@@ -91,13 +91,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(480));
     }
 }
@@ -1,27 +1,28 @@
-use std::{collections::HashSet, thread::sleep, time};
+use std::collections::HashSet;
 
 use advent_of_code::NUM_RE;
 
-advent_of_code::solution!(14);
+advent_of_code::solution!(
+    2024,
+    14,
+    params {
+        width: i32 = 101,
+        height: i32 = 103
+    }
+);
 
-pub fn part_one(input: &str) -> Option<usize> {
+pub fn part_one(input: &str, params: &Params) -> Option<usize> {
     let robots: Vec<_> = input
         .lines()
-        .map(Robot::<101, 103>::from)
+        .map(|line| Robot::parse(line, params))
         .map(|mut robot| {
             robot.simulate(100);
             robot
         })
         .collect();
 
-    Robot::print(&robots);
-
     let quadrants: Vec<_> = robots.iter().filter_map(|robot| robot.quadrant()).collect();
 
-    for quadrant in &quadrants {
-        println!("{:?}", quadrant);
-    }
-
     let safety_factor = [
         Quadrant::First,
         Quadrant::Second,
@@ -35,8 +36,11 @@ pub fn part_one(input: &str) -> Option<usize> {
     Some(safety_factor)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let mut robots: Vec<_> = input.lines().map(Robot::<101, 103>::from).collect();
+pub fn part_two(input: &str, params: &Params) -> Option<u32> {
+    let mut robots: Vec<_> = input
+        .lines()
+        .map(|line| Robot::parse(line, params))
+        .collect();
 
     let mut ticks = 0;
 
@@ -46,18 +50,19 @@ pub fn part_two(input: &str) -> Option<u32> {
         }
         ticks += 1;
     }
-    Robot::print(&robots);
 
     Some(ticks)
 }
 
 #[derive(Debug)]
-struct Robot<const WIDTH: i32, const HEIGHT: i32> {
+struct Robot {
     position: (i32, i32),
     velocity: (i32, i32),
+    width: i32,
+    height: i32,
 }
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq)]
 enum Quadrant {
     First,
     Second,
@@ -65,21 +70,34 @@ enum Quadrant {
     Fourth,
 }
 
-impl<const WIDTH: i32, const HEIGHT: i32> Robot<WIDTH, HEIGHT> {
+impl Robot {
+    fn parse(value: &str, params: &Params) -> Self {
+        let nums: Vec<i32> = NUM_RE
+            .find_iter(value)
+            .map(|n| n.as_str().parse().unwrap())
+            .collect();
+        Self {
+            position: (nums[0], nums[1]),
+            velocity: (nums[2], nums[3]),
+            width: params.width,
+            height: params.height,
+        }
+    }
+
     fn simulate(&mut self, ticks: i32) {
         fn wrap(value: i32, max: i32) -> i32 {
             ((value % max) + max) % max
         }
 
         self.position = (
-            wrap(self.position.0 + self.velocity.0 * ticks, WIDTH),
-            wrap(self.position.1 + self.velocity.1 * ticks, HEIGHT),
+            wrap(self.position.0 + self.velocity.0 * ticks, self.width),
+            wrap(self.position.1 + self.velocity.1 * ticks, self.height),
         );
     }
 
     fn quadrant(&self) -> Option<Quadrant> {
-        let mid_x = WIDTH / 2;
-        let mid_y = HEIGHT / 2;
+        let mid_x = self.width / 2;
+        let mid_y = self.height / 2;
 
         if self.position.0 == mid_x || self.position.1 == mid_y {
             None
@@ -93,58 +111,26 @@ impl<const WIDTH: i32, const HEIGHT: i32> Robot<WIDTH, HEIGHT> {
         }
     }
 
-    fn print(bots: &[Self]) {
-        for i in 0..HEIGHT {
-            for j in 0..WIDTH {
-                let num_bots = bots
-                    .iter()
-                    .filter(|b| b.position.0 == j && b.position.1 == i)
-                    .count();
-                print!(
-                    "{}",
-                    if num_bots == 0 {
-                        ".".to_owned()
-                    } else {
-                        num_bots.to_string()
-                    }
-                );
-            }
-            println!();
-        }
-    }
-
     fn looks_like_a_christmas_tree(robots: &[Self]) -> bool {
         let unique_positions = robots.iter().map(|b| b.position).collect::<HashSet<_>>();
         unique_positions.len() == robots.len()
     }
 }
 
-impl<const WIDTH: i32, const HEIGHT: i32> From<&str> for Robot<WIDTH, HEIGHT> {
-    fn from(value: &str) -> Self {
-        let nums: Vec<i32> = NUM_RE
-            .find_iter(value)
-            .map(|n| n.as_str().parse().unwrap())
-            .collect();
-        Self {
-            position: (nums[0], nums[1]),
-            velocity: (nums[2], nums[3]),
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let params = Params {
+            width: 11,
+            height: 7,
+        };
+        let result = part_one(
+            &advent_of_code::template::read_file("examples", YEAR, DAY),
+            &params,
+        );
+        assert_eq!(result, Some(12));
     }
 }
@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use advent_of_code::{add, indexed_chars_iter};
 
-advent_of_code::solution!(15);
+advent_of_code::solution!(2024, 15);
 
 pub fn part_one(input: &str) -> Option<i32> {
     let mut instructions = vec![];
@@ -172,13 +172,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(10092));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(9021));
     }
 }
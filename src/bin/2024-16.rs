@@ -3,40 +3,51 @@ use std::collections::{BinaryHeap, HashMap, HashSet};
 
 use advent_of_code::{add, find_char_index, indexed_chars};
 
-advent_of_code::solution!(16);
+advent_of_code::solution!(2024, 16, state);
 
-pub fn part_one(input: &str) -> Option<i32> {
-    let (paths, end_idx) = get_best_paths(input);
+/// The cheapest paths to every node, along with the index of the end tile.
+pub struct BestPaths {
+    paths: HashMap<Node, (i32, Vec<Vec<Node>>)>,
+    end_idx: (i32, i32),
+}
+
+pub fn part_one(input: &str) -> (Option<i32>, BestPaths) {
+    let best_paths = get_best_paths(input);
 
-    paths
+    let cost = best_paths
+        .paths
         .iter()
         .filter_map(|(node, (cost, _))| {
-            if node.idx == end_idx {
+            if node.idx == best_paths.end_idx {
                 Some(*cost)
             } else {
                 None
             }
         })
-        .next()
+        .min();
+
+    (cost, best_paths)
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let (paths, end_idx) = get_best_paths(input);
+pub fn part_two(_input: &str, BestPaths { paths, end_idx }: &BestPaths) -> Option<usize> {
+    let ends = || paths.iter().filter(|(node, _)| node.idx == *end_idx);
+    let best_cost = ends().map(|(_, (cost, _))| *cost).min()?;
 
-    let unique_tiles: HashSet<_> = paths
-        .iter()
-        .filter(|(node, _)| node.idx == end_idx)
+    let unique_tiles: HashSet<_> = ends()
+        .filter(|(_, (cost, _))| *cost == best_cost)
         .flat_map(|(_, (_, path_lists))| {
             path_lists
                 .iter()
                 .flat_map(|path| path.iter().map(|n| n.idx))
         })
+        // the paths lead up to the end tile, without it.
+        .chain([*end_idx])
         .collect();
 
     Some(unique_tiles.len())
 }
 
-fn get_best_paths(input: &str) -> (HashMap<Node, (i32, Vec<Vec<Node>>)>, (i32, i32)) {
+fn get_best_paths(input: &str) -> BestPaths {
     let tiles = indexed_chars(input);
 
     let start = Node {
@@ -123,7 +134,10 @@ fn get_best_paths(input: &str) -> (HashMap<Node, (i32, Vec<Vec<Node>>)>, (i32, i
         }
     }
 
-    (paths, end.idx)
+    BestPaths {
+        paths,
+        end_idx: end.idx,
+    }
 }
 
 #[derive(Eq, PartialEq, Hash, PartialOrd, Ord, Clone, Debug)]
@@ -138,13 +152,14 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let (result, _) = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(7036));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let input = advent_of_code::template::read_file("examples", YEAR, DAY);
+        let (_, paths) = part_one(&input);
+        assert_eq!(part_two(&input, &paths), Some(45));
     }
 }
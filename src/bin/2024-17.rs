@@ -1,21 +1,15 @@
-use advent_of_code::NUM_RE;
-use itertools::Itertools;
+use advent_of_code::parse::{parse_value, split_once};
+use advent_of_code::ParseError;
 
-advent_of_code::solution!(17);
+advent_of_code::solution!(2024, 17, parse);
 
-pub fn part_one(input: &str) -> Option<String> {
-    let (register_a, instructions) = parse(input);
+type Program = (usize, Box<[usize]>);
 
-    let outputs = get_outputs(register_a, &instructions)
-        .into_iter()
-        .map(|x| x.to_string())
-        .join(",");
-
-    Some(outputs)
+pub fn part_one((register_a, instructions): &Program) -> Option<Vec<usize>> {
+    Some(get_outputs(*register_a, instructions))
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let (_, instructions) = parse(input);
+pub fn part_two((_, instructions): &Program) -> Option<usize> {
     let len = instructions.len();
 
     let mut shift = 3 * len;
@@ -30,7 +24,7 @@ pub fn part_two(input: &str) -> Option<usize> {
         for start in &starts {
             for j in 0..8 {
                 let block_start = start + (1 << shift) * j;
-                let outputs = get_outputs(block_start, &instructions);
+                let outputs = get_outputs(block_start, instructions);
 
                 if outputs[instruction_idx] == target_num {
                     block_starts.push(block_start);
@@ -44,16 +38,35 @@ pub fn part_two(input: &str) -> Option<usize> {
     starts.into_iter().min()
 }
 
-fn parse(input: &str) -> (usize, Box<[usize]>) {
-    let mut nums = NUM_RE.find_iter(input).map(|x| x.as_str().parse().unwrap());
-
-    let register_a = nums.next().unwrap();
-    nums.next();
-    nums.next();
-
-    let instructions = nums.collect();
-
-    (register_a, instructions)
+fn parse(input: &str) -> Result<Program, ParseError> {
+    let mut lines = input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+
+    // the number, text and value of the next line.
+    let mut next_value = |label: &str| {
+        let (i, line) = lines
+            .next()
+            .ok_or_else(|| ParseError::new(format!("expected `{label}: <value>`.")))?;
+        let (_, value) = split_once(line, ":").map_err(|e| e.on_line(i + 1))?;
+        Ok::<_, ParseError>((i + 1, line, value))
+    };
+
+    let (line_number, line, value) = next_value("Register A")?;
+    let register_a = parse_value(line, value).map_err(|e| e.on_line(line_number))?;
+
+    next_value("Register B")?;
+    next_value("Register C")?;
+
+    let (line_number, line, value) = next_value("Program")?;
+    let instructions = value
+        .split(',')
+        .map(|num| parse_value(line, num))
+        .collect::<Result<_, _>>()
+        .map_err(|e| e.on_line(line_number))?;
+
+    Ok((register_a, instructions))
 }
 
 fn get_outputs(mut register_a: usize, instructions: &[usize]) -> Vec<usize> {
@@ -127,13 +140,15 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let input = advent_of_code::template::read_file("examples", YEAR, DAY);
+        let result = part_one(&parse(&input).unwrap());
+        assert_eq!(result, Some(vec![4, 6, 3, 5, 6, 3, 5, 2, 1, 0]));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let input = advent_of_code::template::read_file_part("examples", YEAR, DAY, 2);
+        let result = part_two(&parse(&input).unwrap());
+        assert_eq!(result, Some(117440));
     }
 }
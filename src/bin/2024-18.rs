@@ -6,29 +6,37 @@ use std::{
 use advent_of_code::{add, get_in_bounds_index_neighbors, manhattan_distance, NUM_RE};
 use itertools::Itertools;
 
-advent_of_code::solution!(18);
+advent_of_code::solution!(
+    2024,
+    18,
+    params {
+        size: i32 = 71,
+        bytes: usize = 1024
+    }
+);
 
-pub fn part_one(input: &str) -> Option<i32> {
-    get_distance_to_exit(input, 1024)
+pub fn part_one(input: &str, params: &Params) -> Option<i32> {
+    get_distance_to_exit(input, params.size, params.bytes)
 }
 
-pub fn part_two(input: &str) -> Option<&str> {
-    let mut left = 1024;
+pub fn part_two(input: &str, params: &Params) -> Option<(i32, i32)> {
+    let mut left = params.bytes;
     let mut right = input.lines().count();
 
     while left < right {
         let mid = left + (right - left) / 2;
-        if get_distance_to_exit(input, mid).is_some() {
+        if get_distance_to_exit(input, params.size, mid).is_some() {
             left = mid + 1;
         } else {
             right = mid;
         }
     }
 
-    Some(input.lines().take(left).last().unwrap())
+    let (x, y) = input.lines().take(left).last()?.split_once(',')?;
+    Some((x.parse().ok()?, y.parse().ok()?))
 }
 
-fn get_distance_to_exit(input: &str, num_bits: usize) -> Option<i32> {
+fn get_distance_to_exit(input: &str, size: i32, num_bits: usize) -> Option<i32> {
     let corrupted_coordinates: HashSet<(i32, i32)> = NUM_RE
         .find_iter(input)
         .map(|x| x.as_str().parse().unwrap())
@@ -36,7 +44,7 @@ fn get_distance_to_exit(input: &str, num_bits: usize) -> Option<i32> {
         .take(num_bits)
         .collect();
 
-    let bounds = (71, 71);
+    let bounds = (size, size);
     let start = (0, 0);
     let target = add(bounds, (-1, -1));
 
@@ -81,13 +89,19 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let params = Params { size: 7, bytes: 12 };
+        let result = part_one(
+            &advent_of_code::template::read_file("examples", YEAR, DAY),
+            &params,
+        );
+        assert_eq!(result, Some(22));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let input = advent_of_code::template::read_file("examples", YEAR, DAY);
+        let params = Params { size: 7, bytes: 12 };
+        let result = part_two(&input, &params);
+        assert_eq!(result, Some((6, 1)));
     }
 }
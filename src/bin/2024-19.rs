@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-advent_of_code::solution!(19);
+advent_of_code::solution!(2024, 19);
 
 pub fn part_one(input: &str) -> Option<usize> {
     Some(get_ways(input).filter(|&ways| ways > 0).count())
@@ -50,13 +50,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(6));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(16));
     }
 }
@@ -5,14 +5,20 @@ use advent_of_code::{
 };
 use itertools::Itertools;
 
-advent_of_code::solution!(20);
+advent_of_code::solution!(
+    2024,
+    20,
+    params {
+        threshold: i32 = 100
+    }
+);
 
-pub fn part_one(input: &str) -> Option<usize> {
-    Some(solve(input, 2, 100))
+pub fn part_one(input: &str, params: &Params) -> Option<usize> {
+    Some(solve(input, 2, params.threshold))
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    Some(solve(input, 20, 100))
+pub fn part_two(input: &str, params: &Params) -> Option<usize> {
+    Some(solve(input, 20, params.threshold))
 }
 
 fn solve(input: &str, cheat_duration: i32, good_cheat_threshold: i32) -> usize {
@@ -86,13 +92,21 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let params = Params { threshold: 50 };
+        let result = part_one(
+            &advent_of_code::template::read_file("examples", YEAR, DAY),
+            &params,
+        );
+        assert_eq!(result, Some(1));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let params = Params { threshold: 50 };
+        let result = part_two(
+            &advent_of_code::template::read_file("examples", YEAR, DAY),
+            &params,
+        );
+        assert_eq!(result, Some(285));
     }
 }
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-advent_of_code::solution!(21);
+advent_of_code::solution!(2024, 21);
 
 pub fn part_one(input: &str) -> Option<usize> {
     Some(solve(input, 2))
@@ -190,13 +190,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(126384));
     }
 }
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use itertools::Itertools;
 use rayon::iter::{IntoParallelRefIterator, ParallelBridge, ParallelIterator};
 
-advent_of_code::solution!(22);
+advent_of_code::solution!(2024, 22);
 
 pub fn part_one(input: &str) -> Option<i64> {
     Some(
@@ -76,13 +76,14 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(37327623));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let input = advent_of_code::template::read_file_part("examples", YEAR, DAY, 2);
+        let result = part_two(&input);
+        assert_eq!(result, Some(23));
     }
 }
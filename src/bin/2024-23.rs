@@ -2,9 +2,15 @@ use std::collections::{HashMap, HashSet};
 
 use itertools::Itertools;
 
-advent_of_code::solution!(23);
+advent_of_code::solution!(
+    2024,
+    23,
+    params {
+        clique_size: usize = 13
+    }
+);
 
-pub fn part_one(input: &str) -> Option<usize> {
+pub fn part_one(input: &str, _params: &Params) -> Option<usize> {
     let graph = make_graph(input);
 
     let cliques = find_cliques(&graph, 3);
@@ -17,12 +23,10 @@ pub fn part_one(input: &str) -> Option<usize> {
     )
 }
 
-pub fn part_two(input: &str) -> Option<String> {
+pub fn part_two<'a>(input: &'a str, params: &Params) -> Option<Vec<&'a str>> {
     let graph = make_graph(input);
 
-    let clique = find_cliques(&graph, 13).into_iter().next().unwrap();
-
-    Some(clique.join(", "))
+    find_cliques(&graph, params.clique_size).into_iter().next()
 }
 
 fn make_graph(input: &str) -> HashMap<&str, Vec<&str>> {
@@ -44,7 +48,7 @@ fn make_graph(input: &str) -> HashMap<&str, Vec<&str>> {
     graph
 }
 
-fn find_cliques<'a>(graph: &'a HashMap<&'a str, Vec<&'a str>>, n: usize) -> HashSet<Vec<&'a str>> {
+fn find_cliques<'a>(graph: &HashMap<&'a str, Vec<&'a str>>, n: usize) -> HashSet<Vec<&'a str>> {
     let mut cliques = HashSet::new();
 
     for &node in graph.keys() {
@@ -100,13 +104,18 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let result = part_one(
+            &advent_of_code::template::read_file("examples", YEAR, DAY),
+            &Params::default(),
+        );
+        assert_eq!(result, Some(7));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let input = advent_of_code::template::read_file("examples", YEAR, DAY);
+        let params = Params { clique_size: 4 };
+        let result = part_two(&input, &params);
+        assert_eq!(result, Some(vec!["co", "de", "ka", "ta"]));
     }
 }
@@ -1,10 +1,19 @@
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::str::FromStr;
 
-advent_of_code::solution!(24);
+use advent_of_code::parse::{parse_lines, parse_value, split_once};
+use advent_of_code::ParseError;
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let (mut registers, gates) = parse(input);
+advent_of_code::solution!(2024, 24, parse);
 
+/// The initial values of the input wires, along with the gates of the circuit.
+pub struct Circuit<'a> {
+    registers: HashMap<&'a str, u8>,
+    gates: Vec<Gate<'a>>,
+}
+
+pub fn part_one(Circuit { registers, gates }: &Circuit) -> Option<u64> {
+    let mut registers = registers.clone();
     let mut queue = VecDeque::from_iter(gates);
 
     while let Some(gate) = queue.pop_front() {
@@ -34,10 +43,7 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(num)
 }
 
-pub fn part_two(input: &str) -> Option<String> {
-    let (_, gates) = parse(input);
-    let gates: Vec<_> = gates.collect();
-
+pub fn part_two(Circuit { gates, .. }: &Circuit) -> Option<String> {
     let mut swapped: HashSet<&str> = HashSet::new();
 
     for i in 1..=44 {
@@ -128,22 +134,25 @@ pub fn part_two(input: &str) -> Option<String> {
     Some(swapped.join(","))
 }
 
-fn parse<'a>(input: &'a str) -> (HashMap<&'a str, u8>, impl Iterator<Item = Gate<'a>>) {
-    let parts: Vec<&str> = input.split("\n\n").collect();
+fn parse(input: &str) -> Result<Circuit<'_>, ParseError> {
+    let (registers_part, gates_part) = input.split_once("\n\n").ok_or_else(|| {
+        ParseError::new("expected an empty line between the wires and the gates.")
+    })?;
 
-    let registers = parts[0]
+    let registers: HashMap<&str, u8> = registers_part
         .lines()
-        .map(|line| {
-            let mut s = line.split(": ");
-            let key = s.next().unwrap();
-            let val = s.next().unwrap().parse::<u8>().unwrap();
-            (key, val)
+        .enumerate()
+        .map(|(i, line)| {
+            let (wire, value) = split_once(line, ":").map_err(|e| e.on_line(i + 1))?;
+            let value = parse_value(line, value).map_err(|e| e.on_line(i + 1))?;
+            Ok((wire, value))
         })
-        .collect();
+        .collect::<Result<_, ParseError>>()?;
 
-    let gates = parts[1].lines().map(|line| Gate::from(line));
+    // the gates start after the wires and the empty line.
+    let gates = parse_lines(gates_part).map_err(|e| e.below(registers_part.lines().count() + 1))?;
 
-    (registers, gates)
+    Ok(Circuit { registers, gates })
 }
 
 struct Gate<'a> {
@@ -167,18 +176,30 @@ impl<'a> Gate<'a> {
     }
 }
 
-impl<'a> From<&'a str> for Gate<'a> {
-    fn from(value: &'a str) -> Self {
-        let parts: Vec<&str> = value.split_whitespace().collect();
-        let mut ins = [parts[0], parts[2]];
+impl<'a> TryFrom<&'a str> for Gate<'a> {
+    type Error = ParseError;
+
+    fn try_from(line: &'a str) -> Result<Self, Self::Error> {
+        let (inputs, out) = split_once(line, "->")?;
+
+        let words: Vec<&str> = inputs.split_whitespace().collect();
+        let [in1, operation, in2] = words[..] else {
+            return Err(ParseError::at(
+                line,
+                line,
+                "expected `<wire> <operation> <wire> -> <wire>`.",
+            ));
+        };
+
+        let mut ins = [in1, in2];
         ins.sort_unstable();
 
-        Gate {
+        Ok(Gate {
             in1: ins[0],
             in2: ins[1],
-            out: parts[4],
-            operation: Operation::from(parts[1]),
-        }
+            out: out.trim(),
+            operation: parse_value(line, operation)?,
+        })
     }
 }
 
@@ -189,13 +210,15 @@ enum Operation {
     OR,
 }
 
-impl From<&str> for Operation {
-    fn from(value: &str) -> Self {
-        match value {
-            "XOR" => Operation::XOR,
-            "AND" => Operation::AND,
-            "OR" => Operation::OR,
-            _ => unreachable!(),
+impl FromStr for Operation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "XOR" => Ok(Operation::XOR),
+            "AND" => Ok(Operation::AND),
+            "OR" => Ok(Operation::OR),
+            _ => Err("expected `AND`, `OR` or `XOR`".into()),
         }
     }
 }
@@ -206,13 +229,8 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let input = advent_of_code::template::read_file("examples", YEAR, DAY);
+        let result = part_one(&parse(&input).unwrap());
+        assert_eq!(result, Some(4));
     }
 }
@@ -1,6 +1,6 @@
 use advent_of_code::indexed_chars_iter;
 
-advent_of_code::solution!(25);
+advent_of_code::solution!(2024, 25);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let (keys, locks) = parse(input);
@@ -51,13 +51,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(3));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, None);
     }
 }
@@ -2,8 +2,11 @@ use std::{collections::HashMap, sync::LazyLock};
 
 use regex::Regex;
 
+pub mod parse;
 pub mod template;
 
+pub use parse::ParseError;
+
 pub static NUM_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[+-]?\d+").unwrap());
 
 pub const DIRECTIONS: [(i32, i32); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
@@ -1,16 +1,23 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{answer, download, read, scaffold, solve, watch};
 use args::{parse, AppArguments};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
+use advent_of_code::template::{Day, Year};
 #[cfg(feature = "today")]
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::commands::scaffold::{ScaffoldOptions, DEFAULT_TEMPLATE};
+    use advent_of_code::template::commands::solve::SolveOptions;
+    use advent_of_code::template::{Day, Year};
     use std::process;
 
     pub enum AppArguments {
+        Answer {
+            day: Day,
+            part: u8,
+            answer: String,
+        },
         Download {
             day: Day,
         },
@@ -19,60 +26,73 @@ mod args {
         },
         Scaffold {
             day: Day,
-            download: bool,
-            overwrite: bool,
+            options: ScaffoldOptions,
         },
         Solve {
             day: Day,
-            release: bool,
-            dhat: bool,
-            submit: Option<u8>,
-        },
-        All {
-            release: bool,
-        },
-        Time {
-            all: bool,
-            day: Option<Day>,
-            store: bool,
+            options: SolveOptions,
+            watch: bool,
         },
         #[cfg(feature = "today")]
         Today,
     }
 
-    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
+    /// Parse the command line. The year defaults to `AOC_YEAR` if `--year` is not passed.
+    pub fn parse() -> Result<(Year, AppArguments), Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
+        let year = match args.opt_value_from_str("--year")? {
+            Some(year) => year,
+            None => Year::from_env().ok_or("no year specified. Pass `--year` or set AOC_YEAR.")?,
+        };
+
         let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
-                release: args.contains("--release"),
+            Some("answer") => AppArguments::Answer {
+                day: args.free_from_str()?,
+                part: args.free_from_str()?,
+                answer: args.free_from_str()?,
             },
-            Some("time") => {
-                let all = args.contains("--all");
-                let store = args.contains("--store");
-
-                AppArguments::Time {
-                    all,
-                    day: args.opt_free_from_str()?,
-                    store,
-                }
-            }
             Some("download") => AppArguments::Download {
                 day: args.free_from_str()?,
             },
             Some("read") => AppArguments::Read {
                 day: args.free_from_str()?,
             },
-            Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
-                download: args.contains("--download"),
-                overwrite: args.contains("--overwrite"),
-            },
+            Some("scaffold") => {
+                let day = args.free_from_str()?;
+                // every flag is read on its own, so none is left over when they are combined.
+                let overwrite = args.contains("--overwrite");
+                let overwrite_module = args.contains("--overwrite-module");
+                let overwrite_example = args.contains("--overwrite-example");
+
+                AppArguments::Scaffold {
+                    day,
+                    options: ScaffoldOptions {
+                        template: args
+                            .opt_value_from_str("--template")?
+                            .unwrap_or_else(|| DEFAULT_TEMPLATE.to_string()),
+                        // `--overwrite` replaces every file that can be replaced.
+                        overwrite_module: overwrite || overwrite_module,
+                        overwrite_example: overwrite || overwrite_example,
+                        download: args.contains("--download"),
+                        // a downloaded input is only ever replaced on request.
+                        overwrite_input: args.contains("--overwrite-input"),
+                        dry_run: args.contains("--dry-run"),
+                    },
+                }
+            }
             Some("solve") => AppArguments::Solve {
                 day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
+                options: SolveOptions {
+                    release: args.contains("--release"),
+                    dhat: args.contains("--dhat"),
+                    json: args.contains("--json"),
+                    time: args.contains("--time"),
+                    all_inputs: args.contains("--all-inputs"),
+                    part: args.opt_value_from_str("--part")?,
+                    submit: args.opt_value_from_str("--submit")?,
+                },
+                watch: args.contains("--watch"),
             },
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
@@ -91,7 +111,7 @@ mod args {
             eprintln!("Warning: unknown argument(s): {remaining:?}.");
         }
 
-        Ok(app_args)
+        Ok((year, app_args))
     }
 }
 
@@ -101,30 +121,32 @@ fn main() {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
-        Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day, download, overwrite } => {
-                scaffold::handle(day, overwrite);
-                if download {
-                    download::handle(day);
-                }
-            }
+        Ok((year, args)) => match args {
+            AppArguments::Answer { day, part, answer } => answer::handle(year, day, part, &answer),
+            AppArguments::Download { day } => download::handle(year, day),
+            AppArguments::Read { day } => read::handle(year, day),
+            AppArguments::Scaffold { day, options } => scaffold::handle(year, day, &options),
             AppArguments::Solve {
                 day,
-                release,
-                dhat,
-                submit,
-            } => solve::handle(day, release, dhat, submit),
+                options,
+                watch,
+            } => {
+                if watch {
+                    watch::handle(year, day, options.release);
+                } else {
+                    solve::handle(year, day, &options);
+                }
+            }
             #[cfg(feature = "today")]
             AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
+                match Year::today().zip(Day::today()) {
+                    Some((year, day)) => {
+                        let options = scaffold::ScaffoldOptions {
+                            download: true,
+                            ..Default::default()
+                        };
+                        scaffold::handle(year, day, &options);
+                        read::handle(year, day)
                     }
                     None => {
                         eprintln!(
@@ -0,0 +1,167 @@
+/// Runs several days of a year in one process. Every scaffolded solution is linked into this binary by `build.rs`.
+use advent_of_code::template::commands::{all, history, status, time};
+use advent_of_code::template::run_day;
+use args::{parse, AppArguments};
+
+#[cfg(not(feature = "dhat-heap"))]
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+// NOTE: every solution declares its own global allocator when profiling with dhat, so they can't be linked together.
+#[cfg(feature = "dhat-heap")]
+pub const SOLUTIONS: &[advent_of_code::template::Solution] = &[];
+
+mod args {
+    use advent_of_code::template::limits::Limits;
+    use advent_of_code::template::selector::{parse_days, DaySelector};
+    use advent_of_code::template::{Day, MultiOptions, Year, DEFAULT_THRESHOLD, RUN_DAY_COMMAND};
+    use std::process;
+
+    pub enum AppArguments {
+        All {
+            selector: DaySelector,
+            options: MultiOptions,
+        },
+        Time {
+            all: bool,
+            selector: DaySelector,
+            store: bool,
+            compare_threshold: Option<f64>,
+            options: MultiOptions,
+        },
+        History {
+            selector: DaySelector,
+        },
+        Status {
+            selector: DaySelector,
+        },
+        RunDay {
+            day: Day,
+            is_timed: bool,
+        },
+    }
+
+    /// Parse the flags of commands that run several days:
+    /// `--timeout <seconds>`, `--max-memory <MiB>`, `--jobs <N>` and `--format text|json|csv|junit`.
+    /// The limits can be repeated and scoped to a day or part, e.g. `--timeout 5 --timeout 12=30 --timeout 6.2=10`.
+    fn parse_options(args: &mut pico_args::Arguments) -> Result<MultiOptions, pico_args::Error> {
+        Ok(MultiOptions {
+            is_timed: false,
+            limits: Limits::from_flags(
+                &args.values_from_str("--timeout")?,
+                &args.values_from_str("--max-memory")?,
+            ),
+            jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
+            format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+        })
+    }
+
+    /// Parse the days a multi-day command runs: a list of days and ranges like `1-10,12`, and the filters
+    /// `--unsolved`, `--changed-since <git-ref>` and `--slowest <N>`. Must come last, since the list is a free argument.
+    fn parse_selector(args: &mut pico_args::Arguments) -> Result<DaySelector, pico_args::Error> {
+        Ok(DaySelector {
+            unsolved: args.contains("--unsolved"),
+            changed_since: args.opt_value_from_str("--changed-since")?,
+            slowest: args.opt_value_from_str("--slowest")?,
+            days: args.opt_free_from_fn(parse_days)?,
+        })
+    }
+
+    /// Parse the command line. The year defaults to `AOC_YEAR` if `--year` is not passed.
+    pub fn parse() -> Result<(Year, AppArguments), Box<dyn std::error::Error>> {
+        let mut args = pico_args::Arguments::from_env();
+
+        let year = match args.opt_value_from_str("--year")? {
+            Some(year) => year,
+            None => Year::from_env().ok_or("no year specified. Pass `--year` or set AOC_YEAR.")?,
+        };
+
+        let app_args = match args.subcommand()?.as_deref() {
+            Some("all") => {
+                let options = parse_options(&mut args)?;
+
+                AppArguments::All {
+                    selector: parse_selector(&mut args)?,
+                    options,
+                }
+            }
+            Some("time") if args.contains("--history") => AppArguments::History {
+                selector: parse_selector(&mut args)?,
+            },
+            Some("time") => {
+                let all = args.contains("--all");
+                let store = args.contains("--store");
+                let compare = args.contains("--compare");
+                let threshold = args.opt_value_from_str("--threshold")?;
+                let options = parse_options(&mut args)?;
+
+                if options.jobs > 1 {
+                    eprintln!("Warning: `time` runs one day at a time, since parallel days would skew the benchmarks.");
+                }
+
+                AppArguments::Time {
+                    all,
+                    selector: parse_selector(&mut args)?,
+                    store,
+                    compare_threshold: (compare || threshold.is_some())
+                        .then(|| threshold.unwrap_or(DEFAULT_THRESHOLD)),
+                    options,
+                }
+            }
+            Some("status") => AppArguments::Status {
+                selector: parse_selector(&mut args)?,
+            },
+            // runs a single day for `all` and `time`, which supervise it in a child process if limits are set.
+            Some(RUN_DAY_COMMAND) => AppArguments::RunDay {
+                is_timed: args.contains("--time"),
+                day: args.free_from_str()?,
+            },
+            Some(x) => {
+                eprintln!("Unknown command: {x}");
+                process::exit(1);
+            }
+            None => {
+                eprintln!("No command specified.");
+                process::exit(1);
+            }
+        };
+
+        let remaining = args.finish();
+        if !remaining.is_empty() {
+            eprintln!("Warning: unknown argument(s): {remaining:?}.");
+        }
+
+        Ok((year, app_args))
+    }
+}
+
+fn main() {
+    match parse() {
+        Err(err) => {
+            eprintln!("Error: {err}");
+            std::process::exit(1);
+        }
+        Ok((year, args)) => match args {
+            AppArguments::All { selector, options } => {
+                all::handle(SOLUTIONS, year, &selector, options);
+            }
+            AppArguments::Time {
+                selector,
+                all,
+                store,
+                compare_threshold,
+                options,
+            } => time::handle(
+                SOLUTIONS,
+                year,
+                &selector,
+                all,
+                store,
+                compare_threshold,
+                options,
+            ),
+            AppArguments::History { selector } => history::handle(SOLUTIONS, year, &selector),
+            AppArguments::Status { selector } => status::handle(SOLUTIONS, year, &selector),
+            AppArguments::RunDay { day, is_timed } => run_day(SOLUTIONS, year, day, is_timed),
+        },
+    };
+}
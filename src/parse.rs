@@ -0,0 +1,160 @@
+/// Module with helpers that parse puzzle inputs into typed values, reporting where an input is malformed.
+/// Types that are parsed from a line implement `TryFrom<&str, Error = ParseError>`, see [`parse_lines`]:
+///
+/// ```ignore
+/// impl TryFrom<&str> for OrderingRule {
+///     type Error = ParseError;
+///
+///     fn try_from(line: &str) -> Result<Self, Self::Error> {
+///         let (left, right) = split_once(line, "|")?;
+///         Ok(Self { left: parse_value(line, left)?, right: parse_value(line, right)? })
+///     }
+/// }
+/// ```
+use std::{error::Error, fmt::Display, str::FromStr};
+
+/// A malformed puzzle input, along with the position of the problem if it is known.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    /// The line of the problem, starting at 1.
+    pub line: Option<usize>,
+    /// The column of the problem in characters, starting at 1.
+    pub column: Option<usize>,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            line: None,
+            column: None,
+        }
+    }
+
+    /// An error at `part` of `line`, where `part` is a slice of `line`.
+    pub fn at(line: &str, part: &str, message: impl Into<String>) -> Self {
+        let offset = (part.as_ptr() as usize).checked_sub(line.as_ptr() as usize);
+        let column = offset
+            .filter(|&offset| offset <= line.len())
+            .map(|offset| line[..offset].chars().count() + 1);
+
+        Self {
+            column,
+            ..Self::new(message)
+        }
+    }
+
+    /// Attach the line of the error, unless it already has one.
+    #[must_use]
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    /// Move the error down by `lines`, for sections of an input that were parsed on their own.
+    #[must_use]
+    pub fn below(mut self, lines: usize) -> Self {
+        self.line = self.line.map(|line| line + lines);
+        self
+    }
+}
+
+/// Parse every line of an input. Errors are reported with their line, and an empty input is an error.
+pub fn parse_lines<'a, T>(input: &'a str) -> Result<Vec<T>, ParseError>
+where
+    T: TryFrom<&'a str, Error = ParseError>,
+{
+    if input.trim().is_empty() {
+        return Err(ParseError::new("the input is empty."));
+    }
+
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| T::try_from(line).map_err(|e| e.on_line(i + 1)))
+        .collect()
+}
+
+/// Parse `part` of `line`, e.g. a number. Surrounding whitespace is ignored.
+pub fn parse_value<T>(line: &str, part: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let value = part.trim();
+    value
+        .parse()
+        .map_err(|e| ParseError::at(line, value, format!("invalid value `{value}`: {e}.")))
+}
+
+/// Split `line` at the first occurrence of `delimiter`.
+pub fn split_once<'a>(line: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    line.split_once(delimiter).ok_or_else(|| {
+        ParseError::at(
+            line,
+            &line[line.len()..],
+            format!("expected `{delimiter}`."),
+        )
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {line}, column {column}: ")?,
+            (Some(line), None) => write!(f, "line {line}: ")?,
+            (None, Some(column)) => write!(f, "column {column}: ")?,
+            (None, None) => {}
+        }
+
+        f.write_str(&self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_lines, parse_value, split_once, ParseError};
+
+    #[derive(Debug, PartialEq)]
+    struct Pair(i32, i32);
+
+    impl TryFrom<&str> for Pair {
+        type Error = ParseError;
+
+        fn try_from(line: &str) -> Result<Self, Self::Error> {
+            let (left, right) = split_once(line, "|")?;
+            Ok(Pair(parse_value(line, left)?, parse_value(line, right)?))
+        }
+    }
+
+    #[test]
+    fn parses_lines() {
+        assert_eq!(
+            parse_lines::<Pair>("47|53\n97| 13\n"),
+            Ok(vec![Pair(47, 53), Pair(97, 13)])
+        );
+    }
+
+    #[test]
+    fn reports_positions() {
+        let error = parse_lines::<Pair>("47|53\n97|1x\n").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(4)));
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 4: invalid value `1x`: invalid digit found in string."
+        );
+
+        let error = parse_lines::<Pair>("47|53\n97 13").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 6: expected `|`.");
+
+        let error = parse_lines::<Pair>("\n").unwrap_err();
+        assert_eq!(error.to_string(), "the input is empty.");
+    }
+}
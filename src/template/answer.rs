@@ -0,0 +1,167 @@
+/// Module that defines the answers solution parts can return.
+/// Parts return `Option<T>` for any `T` that converts into an [`Answer`], e.g. integers, strings or a grid.
+/// Parts that parse their input can return `Result<T, ParseError>` instead, see [`PartOutput`].
+use std::fmt::Display;
+
+use crate::template::ocr;
+use crate::ParseError;
+
+/// The answer of a solution part, as it is displayed, checked and submitted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+    /// ASCII art, one line per row, where `#` or `█` is a lit pixel.
+    /// Displayed as the letters it spells if it uses the block-letter font of the puzzles, see [`Answer::read_letters`].
+    Grid(String),
+}
+
+impl Answer {
+    /// An answer drawn as a grid, from rows of pixels that are lit if `true`.
+    pub fn grid<R: IntoIterator<Item = bool>>(rows: impl IntoIterator<Item = R>) -> Self {
+        let grid = rows
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .map(|lit| if lit { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Answer::Grid(grid)
+    }
+
+    /// The letters a grid spells, or `None` if it is not a grid or not written in the block-letter font.
+    pub fn read_letters(&self) -> Option<String> {
+        match self {
+            Answer::Grid(grid) => ocr::read_letters(grid),
+            _ => None,
+        }
+    }
+}
+
+/// A value that a solution part can return.
+/// `Ok(None)` means the part did not find an answer, an error means its input is malformed.
+pub trait PartOutput {
+    fn into_answer(self) -> Result<Option<Answer>, ParseError>;
+}
+
+impl<T: Into<Answer>> PartOutput for Option<T> {
+    fn into_answer(self) -> Result<Option<Answer>, ParseError> {
+        Ok(self.map(Into::into))
+    }
+}
+
+impl<T: Into<Answer>> PartOutput for Result<T, ParseError> {
+    fn into_answer(self) -> Result<Option<Answer>, ParseError> {
+        self.map(|answer| Some(answer.into()))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl Display for Answer {
+    /// Grids that can't be read as letters are written as-is and span multiple lines.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(value) => write!(f, "{value}"),
+            Answer::Text(text) => f.write_str(text),
+            Answer::Grid(grid) => match ocr::read_letters(grid) {
+                Some(letters) => f.write_str(&letters),
+                None => f.write_str(grid),
+            },
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(value: $ty) -> Self {
+                    Answer::Int(i128::from(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(u8, u16, u32, u64, i8, i16, i32, i64, i128);
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Int(value as i128)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Answer::Int(value as i128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl From<char> for Answer {
+    fn from(value: char) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// A list of values is answered separated by commas, e.g. `co,de,ka,ta`.
+impl<T: Display> From<Vec<T>> for Answer {
+    fn from(values: Vec<T>) -> Self {
+        let values: Vec<String> = values.iter().map(ToString::to_string).collect();
+        Answer::Text(values.join(","))
+    }
+}
+
+/// A pair of values is answered as coordinates, e.g. `6,1`.
+impl<A: Display, B: Display> From<(A, B)> for Answer {
+    fn from((a, b): (A, B)) -> Self {
+        Answer::Text(format!("{a},{b}"))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Answer;
+
+    #[test]
+    fn converts_values() {
+        assert_eq!(Answer::from(41usize), Answer::Int(41));
+        assert_eq!(Answer::from(-3i64).to_string(), "-3");
+        assert_eq!(Answer::from("6,1"), Answer::Text("6,1".into()));
+        assert_eq!(Answer::from(vec!["co", "de", "ka"]).to_string(), "co,de,ka");
+        assert_eq!(Answer::from((6, 1)).to_string(), "6,1");
+    }
+
+    #[test]
+    fn displays_grids() {
+        let hi = Answer::grid(
+            [
+                "#..#.###", "#..#..#.", "####..#.", "#..#..#.", "#..#..#.", "#..#.###",
+            ]
+            .map(|row| row.chars().map(|c| c == '#').collect::<Vec<_>>()),
+        );
+        assert_eq!(hi.read_letters().as_deref(), Some("HI"));
+        assert_eq!(hi.to_string(), "HI");
+
+        let unreadable = Answer::Grid("#.\n.#".into());
+        assert_eq!(unreadable.read_letters(), None);
+        assert_eq!(unreadable.to_string(), "#.\n.#");
+    }
+}
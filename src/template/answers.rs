@@ -0,0 +1,223 @@
+/// Module that stores the accepted answers of each day, so solutions can be checked against them.
+/// Answers live in `data/YYYY/answers/NN.json` and are written after a correct submission or via `cargo answer`.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::Error,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::{data_dir, Day, Year};
+
+/// The known-good answers of a single day.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Answers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+/// The result of checking an answer against the stored one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Check {
+    Correct,
+    Wrong,
+    /// There is no stored answer for this part.
+    Unknown,
+}
+
+impl Answers {
+    /// Rehydrate the answers of a day. If not present, returns empty answers.
+    pub fn read_from_file(year: Year, day: Day) -> Self {
+        Self::read_from_path(&get_path(year, day))
+    }
+
+    /// Rehydrate the answers of one of several inputs of a day, see [`inputs`](crate::template::inputs).
+    pub fn read_for_input(year: Year, day: Day, input: &str) -> Self {
+        Self::read_from_path(
+            &get_dir(year)
+                .join(day.to_string())
+                .join(format!("{input}.json")),
+        )
+    }
+
+    fn read_from_path(path: &Path) -> Self {
+        match fs::read_to_string(path) {
+            Ok(s) => Answers::try_from(s).unwrap_or_else(|e| {
+                eprintln!("Ignoring answers in {}: {e}", path.display());
+                Answers::default()
+            }),
+            Err(_) => Answers::default(),
+        }
+    }
+
+    /// Dehydrate the answers of a day to a JSON file.
+    pub fn store_file(&self, year: Year, day: Day) -> Result<(), Error> {
+        fs::create_dir_all(get_dir(year))?;
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(get_path(year, day))?;
+        json.format_to(&mut file)
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: &str) {
+        let answer = Some(answer.to_string());
+        match part {
+            1 => self.part_1 = answer,
+            2 => self.part_2 = answer,
+            _ => {}
+        }
+    }
+
+    /// Check an answer against the stored answer for a part.
+    pub fn check(&self, part: u8, answer: Option<&str>) -> Check {
+        match (self.get(part), answer) {
+            (None, _) => Check::Unknown,
+            (Some(expected), Some(answer)) if expected == answer => Check::Correct,
+            (Some(_), _) => Check::Wrong,
+        }
+    }
+}
+
+fn get_dir(year: Year) -> PathBuf {
+    data_dir(year).join("answers")
+}
+
+fn get_path(year: Year, day: Day) -> PathBuf {
+    get_dir(year).join(format!("{day}.json"))
+}
+
+/// Store the answer of a part, keeping the other part's answer.
+pub fn store_answer(year: Year, day: Day, part: u8, answer: &str) -> Result<(), Error> {
+    let mut answers = Answers::read_from_file(year, day);
+    answers.set(part, answer);
+    answers.store_file(year, day)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl Display for Check {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Check::Correct => "correct",
+            Check::Wrong => "wrong",
+            Check::Unknown => "unknown",
+        })
+    }
+}
+
+impl FromStr for Check {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Check::Correct),
+            "wrong" => Ok(Check::Wrong),
+            "unknown" => Ok(Check::Unknown),
+            _ => Err(format!("unknown check `{s}`.")),
+        }
+    }
+}
+
+impl From<&Answers> for JsonValue {
+    fn from(value: &Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        let answer = |answer: &Option<String>| match answer {
+            Some(answer) => JsonValue::String(answer.clone()),
+            None => JsonValue::Null,
+        };
+
+        map.insert("part_1".into(), answer(&value.part_1));
+        map.insert("part_2".into(), answer(&value.part_2));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let answer = |key: &str| match json.get(key) {
+            None | Some(JsonValue::Null) => Ok(None),
+            Some(JsonValue::String(answer)) => Ok(Some(answer.clone())),
+            Some(_) => Err(format!("expected `{key}` to be null or string.")),
+        };
+
+        Ok(Answers {
+            part_1: answer("part_1")?,
+            part_2: answer("part_2")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{Answers, Check};
+
+    #[test]
+    fn checks_answers() {
+        let answers = Answers {
+            part_1: Some("42".into()),
+            part_2: None,
+        };
+
+        assert_eq!(answers.check(1, Some("42")), Check::Correct);
+        assert_eq!(answers.check(1, Some("43")), Check::Wrong);
+        assert_eq!(answers.check(1, None), Check::Wrong);
+        assert_eq!(answers.check(2, Some("42")), Check::Unknown);
+    }
+
+    #[test]
+    fn sets_answers() {
+        let mut answers = Answers::default();
+        answers.set(2, "abc");
+        assert_eq!(answers.get(1), None);
+        assert_eq!(answers.get(2), Some("abc"));
+    }
+
+    #[test]
+    fn round_trips_answers() {
+        let answers = Answers {
+            part_1: Some("1,2,3".into()),
+            part_2: None,
+        };
+
+        let json = JsonValue::from(&answers).stringify().unwrap();
+        assert_eq!(Answers::try_from(json).unwrap(), answers);
+    }
+
+    #[test]
+    fn handles_partial_answers() {
+        let answers = Answers::try_from(r#"{ "part_2": "9" }"#.to_string()).unwrap();
+        assert_eq!(answers.part_1, None);
+        assert_eq!(answers.part_2.as_deref(), Some("9"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_answers() {
+        Answers::try_from(r#"{ "part_1": 42 }"#.to_string()).unwrap();
+    }
+}
@@ -0,0 +1,306 @@
+/// Module that talks to the Advent of Code website.
+/// The session cookie is read from `AOC_SESSION`, or from the file at `AOC_SESSION_FILE` (default: `~/.adventofcode.session`).
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::template::markdown::puzzle_to_markdown;
+use crate::template::submissions::Verdict;
+use crate::template::{data_dir, Day, Year};
+
+static DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+static USER_AGENT: &str = "github.com/fspoettel/advent-of-code-rust";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    MissingSession,
+    /// The server responded with an error status.
+    Status(u16, String),
+    /// The request could not be sent, e.g. because the network is down.
+    Transport(String),
+    IO(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::MissingSession => write!(
+                f,
+                "no session cookie found. Set AOC_SESSION or store it in ~/.adventofcode.session."
+            ),
+            AocClientError::Status(404, _) => {
+                write!(f, "the puzzle was not found. Is it unlocked yet?")
+            }
+            AocClientError::Status(400, _) => {
+                write!(f, "the request was rejected. Is the session cookie valid?")
+            }
+            AocClientError::Status(status, _) => {
+                write!(f, "the server responded with status {status}.")
+            }
+            AocClientError::Transport(e) => write!(f, "the request failed: {e}"),
+            AocClientError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for AocClientError {}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, response) => {
+                AocClientError::Status(status, response.into_string().unwrap_or_default())
+            }
+            ureq::Error::Transport(e) => AocClientError::Transport(e.to_string()),
+        }
+    }
+}
+
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// Configure a client from the environment. `AOC_BASE_URL` overrides the server, e.g. for testing.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+
+        Ok(Self::new(&base_url, &read_session()?))
+    }
+
+    /// Download the puzzle input of a day.
+    pub fn input(&self, year: Year, day: Day) -> Result<String, AocClientError> {
+        let response = self
+            .agent
+            .get(&self.url(year, day, "/input"))
+            .set("Cookie", &self.cookie())
+            .call()?;
+
+        Ok(response.into_string()?)
+    }
+
+    /// Download the puzzle page of a day as HTML.
+    pub fn puzzle(&self, year: Year, day: Day) -> Result<String, AocClientError> {
+        let response = self
+            .agent
+            .get(&self.url(year, day, ""))
+            .set("Cookie", &self.cookie())
+            .call()?;
+
+        Ok(response.into_string()?)
+    }
+
+    /// Post an answer. Returns the server's response converted to Markdown.
+    pub fn submit(
+        &self,
+        year: Year,
+        day: Day,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, AocClientError> {
+        let response = self
+            .agent
+            .post(&self.url(year, day, "/answer"))
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?;
+
+        Ok(puzzle_to_markdown(&response.into_string()?))
+    }
+
+    fn url(&self, year: Year, day: Day, path: &str) -> String {
+        format!("{}/{year}/day/{}{path}", self.base_url, day.into_inner())
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+fn read_session() -> Result<String, AocClientError> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        if !session.trim().is_empty() {
+            return Ok(session.trim().to_string());
+        }
+    }
+
+    let path = match env::var("AOC_SESSION_FILE") {
+        Ok(path) => PathBuf::from(path),
+        Err(_) => {
+            let home = env::var("HOME")
+                .or_else(|_| env::var("USERPROFILE"))
+                .map_err(|_| AocClientError::MissingSession)?;
+            PathBuf::from(home).join(".adventofcode.session")
+        }
+    };
+
+    match fs::read_to_string(path) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+        _ => Err(AocClientError::MissingSession),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+pub fn read(year: Year, day: Day) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
+    let puzzle = puzzle_to_markdown(&client.puzzle(year, day)?);
+
+    let puzzle_path = get_puzzle_path(year, day);
+    create_parent_dir(&puzzle_path)?;
+    fs::write(puzzle_path, &puzzle)?;
+    println!("{puzzle}");
+    Ok(())
+}
+
+/// Download the input of a day and its puzzle, converted to markdown.
+/// Nothing is written, see the `scaffold` command for how the files are stored.
+pub fn download(year: Year, day: Day) -> Result<(String, String), AocClientError> {
+    let client = AocClient::from_env()?;
+    let input = client.input(year, day)?;
+    let puzzle = puzzle_to_markdown(&client.puzzle(year, day)?);
+    Ok((input, puzzle))
+}
+
+pub fn submit(year: Year, day: Day, part: u8, result: &str) -> Result<String, AocClientError> {
+    let client = AocClient::from_env()?;
+    let response = client.submit(year, day, part, result)?;
+    println!("{response}");
+    Ok(response)
+}
+
+/// Whether the response to `submit` reports the answer as correct.
+/// See `Verdict::from_output` for the other verdicts.
+pub fn is_correct_answer(response: &str) -> bool {
+    Verdict::from_output(response) == Verdict::Correct
+}
+
+fn get_puzzle_path(year: Year, day: Day) -> PathBuf {
+    data_dir(year).join("puzzles").join(format!("{day}.md"))
+}
+
+fn create_parent_dir(path: &Path) -> io::Result<()> {
+    match path.parent() {
+        Some(dir) => fs::create_dir_all(dir),
+        None => Ok(()),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    use super::{AocClient, AocClientError};
+    use crate::{day, template::Year, year};
+
+    const YEAR: Year = year!(2024);
+
+    /// Serve a single request with a canned response. Joining the handle returns the raw request.
+    fn mock_server(status: u16, body: &str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let body = body.to_string();
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+                request.push_str(&line);
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+            }
+
+            let mut request_body = vec![0; content_length];
+            reader.read_exact(&mut request_body).unwrap();
+            request.push_str(&String::from_utf8(request_body).unwrap());
+
+            write!(
+                stream,
+                "HTTP/1.1 {status} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn downloads_inputs() {
+        let (base_url, server) = mock_server(200, "1 2\n3 4\n");
+        let client = AocClient::new(&base_url, "abc");
+
+        assert_eq!(client.input(YEAR, day!(1)).unwrap(), "1 2\n3 4\n");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2024/day/1/input HTTP/1.1"));
+        assert!(request.contains("session=abc"));
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (base_url, server) = mock_server(
+            200,
+            "<main><article><p>That's the right answer! <a href=\"/2024\">[Return]</a></p></article></main>",
+        );
+        let client = AocClient::new(&base_url, "abc");
+
+        assert_eq!(
+            client.submit(YEAR, day!(12), 2, "42").unwrap(),
+            "That's the right answer! [[Return]](/2024)"
+        );
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2024/day/12/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=42"));
+    }
+
+    #[test]
+    fn reports_error_statuses() {
+        let (base_url, server) = mock_server(404, "Not Found");
+        let client = AocClient::new(&base_url, "abc");
+
+        let result = client.puzzle(YEAR, day!(25));
+        server.join().unwrap();
+
+        assert!(matches!(result, Err(AocClientError::Status(404, body)) if body == "Not Found"));
+    }
+}
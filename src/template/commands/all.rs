@@ -1,5 +1,14 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::process;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::selector::DaySelector;
+use crate::template::{run_multi::run_multi, MultiOptions, Solution, Year};
+
+/// Run the selected days of a year, every day by default. See [`run_multi`].
+pub fn handle(solutions: &[Solution], year: Year, selector: &DaySelector, options: MultiOptions) {
+    let days = selector.select(solutions, year).unwrap_or_else(|e| {
+        eprintln!("Failed to select days: {e}");
+        process::exit(1);
+    });
+
+    run_multi(solutions, year, &days, options);
 }
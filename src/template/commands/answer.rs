@@ -0,0 +1,18 @@
+use std::process;
+
+use crate::template::answers::store_answer;
+use crate::template::{Day, Year};
+
+pub fn handle(year: Year, day: Day, part: u8, answer: &str) {
+    if part != 1 && part != 2 {
+        eprintln!("Unexpected part {part}, expecting 1 or 2.");
+        process::exit(1);
+    }
+
+    if let Err(e) = store_answer(year, day, part, answer) {
+        eprintln!("Failed to store answer: {e}");
+        process::exit(1);
+    }
+
+    println!("Stored answer \"{answer}\" for {year} day {day}, part {part}.");
+}
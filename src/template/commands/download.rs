@@ -1,14 +1,18 @@
-use crate::template::{aoc_cli, Day};
 use std::process;
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+use crate::template::commands::scaffold::{download_artifacts, write_artifacts};
+use crate::template::{aoc_client, Day, Year};
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
-    };
+/// Download the input and puzzle of a day. Existing files are replaced and backed up, like `scaffold` does.
+pub fn handle(year: Year, day: Day) {
+    match aoc_client::download(year, day) {
+        Ok(downloaded) => write_artifacts(
+            &download_artifacts(year, day, Some(downloaded), true),
+            false,
+        ),
+        Err(e) => {
+            eprintln!("failed to download {year} day {day}: {e}");
+            process::exit(1);
+        }
+    }
 }
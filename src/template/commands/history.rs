@@ -0,0 +1,36 @@
+use std::collections::HashSet;
+use std::process;
+
+use crate::template::history::{print_history, History};
+use crate::template::selector::DaySelector;
+use crate::template::{all_days, Day, Solution, Year};
+
+/// Print the stored benchmarks of the selected days.
+/// Without a selection, every day that has been stored is printed.
+pub fn handle(solutions: &[Solution], year: Year, selector: &DaySelector) {
+    let history = History::read_from_file(year);
+
+    let days: HashSet<Day> = if selector.is_empty() {
+        history
+            .entries
+            .iter()
+            .flat_map(|entry| entry.timings.data.iter().map(|timing| timing.day))
+            .collect()
+    } else {
+        selector.select(solutions, year).unwrap_or_else(|e| {
+            eprintln!("Failed to select days: {e}");
+            process::exit(1);
+        })
+    };
+
+    if days.is_empty() {
+        println!("No stored timings. Run `cargo time --store` to record one.");
+    }
+
+    for (i, day) in all_days().filter(|day| days.contains(day)).enumerate() {
+        if i > 0 {
+            println!();
+        }
+        print_history(&history, day);
+    }
+}
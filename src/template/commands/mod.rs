@@ -1,6 +1,10 @@
 pub mod all;
+pub mod answer;
 pub mod download;
+pub mod history;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
+pub mod status;
 pub mod time;
+pub mod watch;
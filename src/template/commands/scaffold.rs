@@ -1,79 +1,418 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs, io,
+    path::{Path, PathBuf},
     process,
 };
 
-use crate::template::Day;
+use crate::template::examples::Example;
+use crate::template::inputs::list_txt_files;
+use crate::template::markdown::{example_answer, first_code_block, puzzle_title};
+use crate::template::{aoc_client, data_dir, Day, Year};
 
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+/// Module templates are read from `templates/<name>.txt`.
+const TEMPLATES_DIR: &str = "templates";
 
-fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
-    let mut file = OpenOptions::new();
-    if overwrite {
-        file.create(true);
-    } else {
-        file.create_new(true);
-    }
-    file.truncate(true).write(true).open(path)
+/// The template that is used if `--template` is not passed.
+pub const DEFAULT_TEMPLATE: &str = "default";
+
+/// Used if the templates directory has no default template.
+const BUILTIN_TEMPLATE: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/templates/default.txt"
+));
+
+/// How the files of a day are scaffolded.
+#[derive(Clone, Debug)]
+pub struct ScaffoldOptions {
+    /// The name of the module template, see [`DEFAULT_TEMPLATE`].
+    pub template: String,
+    /// Replace a module file that already has content.
+    pub overwrite_module: bool,
+    /// Replace an example file that already has content.
+    pub overwrite_example: bool,
+    /// Download the input and the puzzle before scaffolding, so the example and title can be taken from the puzzle.
+    pub download: bool,
+    /// Replace an input file that already has content with the downloaded input.
+    pub overwrite_input: bool,
+    /// Only list what would be created, without writing anything.
+    pub dry_run: bool,
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+impl Default for ScaffoldOptions {
+    fn default() -> Self {
+        Self {
+            template: DEFAULT_TEMPLATE.to_string(),
+            overwrite_module: false,
+            overwrite_example: false,
+            download: false,
+            overwrite_input: false,
+            dry_run: false,
+        }
+    }
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// What happens to a scaffolded file.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Action {
+    /// The file is missing or empty.
+    Create,
+    /// The file has content that is backed up and replaced.
+    Replace,
+    /// The file has content that is kept.
+    Keep,
+}
 
-    let mut file = match safe_create_file(&module_path, overwrite) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {e}");
-            process::exit(1);
+impl Action {
+    pub(crate) fn plan(existing: Option<&str>, overwrite: bool) -> Self {
+        match existing {
+            Some(contents) if !contents.trim().is_empty() => {
+                if overwrite {
+                    Action::Replace
+                } else {
+                    Action::Keep
+                }
+            }
+            _ => Action::Create,
         }
-    };
+    }
+}
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
-            process::exit(1);
+/// A file that is created by scaffolding or downloading.
+pub(crate) struct Artifact {
+    /// Describes the file in messages, e.g. `module`.
+    pub kind: &'static str,
+    pub path: PathBuf,
+    pub contents: String,
+    pub action: Action,
+    /// The flag that replaces a file with content, if it can be replaced.
+    pub overwrite_flag: Option<&'static str>,
+}
+
+impl Artifact {
+    /// Describe what happened to the file, or what would happen in a dry run.
+    fn describe(&self, dry_run: bool, backup: Option<&Path>) -> String {
+        let path = self.path.display();
+        let kind = self.kind;
+        let backup = backup
+            .map(|backup| backup.display().to_string())
+            .unwrap_or_default();
+
+        match (self.action, dry_run) {
+            (Action::Create, false) => format!("Created {kind} file \"{path}\""),
+            (Action::Create, true) => format!("Would create {kind} file \"{path}\""),
+            (Action::Replace, false) => {
+                format!("Replaced {kind} file \"{path}\", backed up to \"{backup}\"")
+            }
+            (Action::Replace, true) => {
+                format!("Would replace {kind} file \"{path}\", backing up to \"{backup}\"")
+            }
+            (Action::Keep, _) => match self.overwrite_flag {
+                Some(flag) => {
+                    format!("Kept existing {kind} file \"{path}\" (pass {flag} to replace it)")
+                }
+                None => format!("Kept existing {kind} file \"{path}\""),
+            },
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
-        }
+    /// Write the file, returning the path of the backup if it was replaced.
+    fn write(&self) -> io::Result<Option<PathBuf>> {
+        let backup = match self.action {
+            Action::Keep => return Ok(None),
+            Action::Replace => {
+                let backup = backup_path(&self.path);
+                fs::copy(&self.path, &backup)?;
+                Some(backup)
+            }
+            Action::Create => {
+                if let Some(parent) = self.path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                None
+            }
+        };
+
+        fs::write(&self.path, &self.contents)?;
+        Ok(backup)
     }
+}
+
+/// The first free backup path of a file, e.g. `01.txt.bak` or `01.txt.bak.2` if that is taken.
+fn backup_path(path: &Path) -> PathBuf {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    (1..)
+        .map(|i| match i {
+            1 => path.with_file_name(format!("{file_name}.bak")),
+            i => path.with_file_name(format!("{file_name}.bak.{i}")),
+        })
+        .find(|backup| !backup.exists())
+        .unwrap()
+}
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+/// Read a module template by name, falling back to the built-in default template.
+fn read_template(name: &str) -> io::Result<String> {
+    let path = Path::new(TEMPLATES_DIR).join(format!("{name}.txt"));
+    match fs::read_to_string(path) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound && name == DEFAULT_TEMPLATE => {
+            Ok(BUILTIN_TEMPLATE.to_string())
         }
+        result => result,
+    }
+}
+
+/// The names of the templates in the templates directory.
+fn available_templates() -> Vec<String> {
+    list_txt_files(Path::new(TEMPLATES_DIR))
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|path| path.file_stem())
+        .map(|stem| stem.to_string_lossy().to_string())
+        .collect()
+}
+
+/// The values of the placeholders a template can use:
+///  - `%YEAR%`, `%DAY%` (e.g. `06`) and `%DAY_NUMBER%` (e.g. `6`).
+///  - `%TITLE%`, the title of the puzzle if it was downloaded, e.g. `Day 6: Guard Gallivant`.
+///  - `%EXAMPLE_PART_1%` and `%EXAMPLE_PART_2%`, the expected answers of the example if known, `?` otherwise.
+fn placeholders(
+    year: Year,
+    day: Day,
+    puzzle: Option<&str>,
+    example: &Example,
+) -> Vec<(&'static str, String)> {
+    let title = puzzle
+        .and_then(puzzle_title)
+        .unwrap_or_else(|| format!("Day {}", day.into_inner()));
+
+    vec![
+        ("%YEAR%", year.to_string()),
+        ("%DAY%", day.to_string()),
+        ("%DAY_NUMBER%", day.into_inner().to_string()),
+        ("%TITLE%", title),
+        (
+            "%EXAMPLE_PART_1%",
+            example.part_1.clone().unwrap_or_else(|| "?".into()),
+        ),
+        (
+            "%EXAMPLE_PART_2%",
+            example.part_2.clone().unwrap_or_else(|| "?".into()),
+        ),
+    ]
+}
+
+/// The example of a day, taken from the downloaded puzzle if possible.
+/// The expected answers are filled in once known, the example goes below the `---` line.
+fn puzzle_example(day: Day, puzzle: Option<&str>) -> Example {
+    Example {
+        name: day.to_string(),
+        text: puzzle.and_then(first_code_block).unwrap_or_default(),
+        part_1: puzzle.and_then(example_answer),
+        ..Example::default()
+    }
+}
+
+fn render(template: &str, placeholders: &[(&str, String)]) -> String {
+    placeholders
+        .iter()
+        .fold(template.to_string(), |rendered, (placeholder, value)| {
+            rendered.replace(placeholder, value)
+        })
+}
+
+/// The input and puzzle of a day. Without downloaded contents, e.g. in a dry run or without `--download`,
+/// a missing input is created empty and the puzzle is left out.
+pub(crate) fn download_artifacts(
+    year: Year,
+    day: Day,
+    downloaded: Option<(String, String)>,
+    overwrite_input: bool,
+) -> Vec<Artifact> {
+    let input_path = data_dir(year).join("inputs").join(format!("{day}.txt"));
+    let puzzle_path = data_dir(year).join("puzzles").join(format!("{day}.md"));
+    let (input, puzzle) = downloaded.unzip();
+
+    // an unchanged file is kept, so downloading again doesn't pile up backups.
+    let existing_input = fs::read_to_string(&input_path).ok();
+    let is_input_changed = input.is_none() || existing_input != input;
+
+    let mut artifacts = vec![Artifact {
+        kind: "input",
+        action: Action::plan(
+            existing_input.as_deref(),
+            overwrite_input && is_input_changed,
+        ),
+        contents: input.unwrap_or_default(),
+        path: input_path,
+        overwrite_flag: is_input_changed.then_some("--download --overwrite-input"),
+    }];
+
+    // the puzzle only grows, e.g. once part two is unlocked, so a changed puzzle replaces it.
+    if let Some(puzzle) = puzzle {
+        let existing = fs::read_to_string(&puzzle_path).ok();
+        artifacts.push(Artifact {
+            kind: "puzzle",
+            action: Action::plan(existing.as_deref(), existing.as_ref() != Some(&puzzle)),
+            contents: puzzle,
+            path: puzzle_path,
+            overwrite_flag: None,
+        });
+    }
+
+    artifacts
+}
+
+/// Write the files, backing up any that are replaced, and print what happened to each.
+/// A dry run only prints what would happen.
+pub(crate) fn write_artifacts(artifacts: &[Artifact], dry_run: bool) {
+    for artifact in artifacts {
+        let backup = if dry_run {
+            (artifact.action == Action::Replace).then(|| backup_path(&artifact.path))
+        } else {
+            match artifact.write() {
+                Ok(backup) => backup,
+                Err(e) => {
+                    eprintln!("Failed to write {} file: {e}", artifact.kind);
+                    process::exit(1);
+                }
+            }
+        };
+
+        println!("{}", artifact.describe(dry_run, backup.as_deref()));
+    }
+}
+
+pub fn handle(year: Year, day: Day, options: &ScaffoldOptions) {
+    let module_template = match read_template(&options.template) {
+        Ok(module_template) => module_template,
         Err(e) => {
-            eprintln!("Failed to create example file: {e}");
+            eprintln!("Failed to read template \"{}\": {e}", options.template);
+            eprintln!("Available templates: {}", available_templates().join(", "));
             process::exit(1);
         }
+    };
+
+    // download first, so that the example and title can be taken from the puzzle. A dry run downloads nothing.
+    let downloaded = (options.download && !options.dry_run).then(|| {
+        aoc_client::download(year, day).unwrap_or_else(|e| {
+            eprintln!("Failed to download {year} day {day}: {e}");
+            process::exit(1);
+        })
+    });
+
+    let module_path = PathBuf::from(format!("src/bin/{year}-{day}.rs"));
+    let example_path = data_dir(year).join("examples").join(format!("{day}.txt"));
+    let puzzle_path = data_dir(year).join("puzzles").join(format!("{day}.md"));
+
+    let puzzle = match &downloaded {
+        Some((_, puzzle)) => Some(puzzle.clone()),
+        None => fs::read_to_string(puzzle_path).ok(),
+    };
+    let existing_example = fs::read_to_string(&example_path).ok();
+
+    // a kept example still provides the placeholders of the module.
+    let example_action = Action::plan(existing_example.as_deref(), options.overwrite_example);
+    let example = match (example_action, existing_example) {
+        (Action::Keep, Some(contents)) => Example::parse(&day.to_string(), &contents),
+        _ => puzzle_example(day, puzzle.as_deref()),
+    };
+
+    let placeholders = placeholders(year, day, puzzle.as_deref(), &example);
+
+    let mut artifacts = vec![Artifact {
+        kind: "module",
+        action: Action::plan(
+            fs::read_to_string(&module_path).ok().as_deref(),
+            options.overwrite_module,
+        ),
+        contents: render(&module_template, &placeholders),
+        path: module_path,
+        overwrite_flag: Some("--overwrite-module"),
+    }];
+
+    // an input with content is only replaced by a downloaded input, never by an empty file.
+    let overwrite_input = options.download && options.overwrite_input;
+    artifacts.extend(download_artifacts(year, day, downloaded, overwrite_input));
+
+    artifacts.push(Artifact {
+        kind: "example",
+        action: example_action,
+        contents: example.to_file(),
+        path: example_path,
+        overwrite_flag: Some("--overwrite-example"),
+    });
+
+    write_artifacts(&artifacts, options.dry_run);
+
+    if options.dry_run {
+        return;
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    if Year::from_env() == Some(year) {
+        println!("🎄 Type `cargo solve {day}` to run your solution.");
+    } else {
+        println!("🎄 Type `cargo solve {day} --year {year}` to run your solution.");
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs, path::Path, process};
+
+    use super::{backup_path, download_artifacts, render, Action};
+    use crate::{day, year};
+
+    #[test]
+    fn plans_actions() {
+        assert_eq!(Action::plan(None, false), Action::Create);
+        assert_eq!(Action::plan(Some(" \n"), false), Action::Create);
+        assert_eq!(Action::plan(Some("1 2"), false), Action::Keep);
+        assert_eq!(Action::plan(Some("1 2"), true), Action::Replace);
+    }
+
+    #[test]
+    fn plans_downloads() {
+        // there is no data for this year, so every file is created.
+        let artifacts = download_artifacts(year!(2015), day!(1), None, false);
+        assert_eq!(artifacts.len(), 1);
+        assert_eq!(artifacts[0].action, Action::Create);
+        assert_eq!(artifacts[0].contents, "");
+
+        let downloaded = ("1 2\n".to_string(), "# Day 1".to_string());
+        let artifacts = download_artifacts(year!(2015), day!(1), Some(downloaded), false);
+        let kinds: Vec<_> = artifacts.iter().map(|artifact| artifact.kind).collect();
+        assert_eq!(kinds, ["input", "puzzle"]);
+        assert_eq!(artifacts[0].contents, "1 2\n");
+        assert_eq!(artifacts[1].path, Path::new("data/2015/puzzles/01.md"));
+    }
+
+    #[test]
+    fn finds_free_backup_paths() {
+        let dir = env::temp_dir().join(format!("aoc-scaffold-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("01.txt");
+
+        let first = backup_path(&path);
+        fs::write(&first, "").unwrap();
+        let second = backup_path(&path);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(first, dir.join("01.txt.bak"));
+        assert_eq!(second, dir.join("01.txt.bak.2"));
+    }
+
+    #[test]
+    fn renders_placeholders() {
+        let placeholders = [("%DAY%", "06".into()), ("%DAY_NUMBER%", "6".into())];
+        assert_eq!(
+            render("solution!(%DAY_NUMBER%); // %DAY%", &placeholders),
+            "solution!(6); // 06"
+        );
+    }
 }
@@ -1,24 +1,62 @@
 use std::process::{Command, Stdio};
 
-use crate::template::Day;
+use crate::template::{Day, Year};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// How a day is run by `cargo solve`.
+#[derive(Clone, Debug, Default)]
+pub struct SolveOptions {
+    pub release: bool,
+    /// Run with the heap profiler, see the `dhat-heap` feature.
+    pub dhat: bool,
+    pub json: bool,
+    /// Bench the parts instead of running them once.
+    pub time: bool,
+    /// Run every input of the day instead of the main one.
+    pub all_inputs: bool,
+    /// Only run a single part, e.g. to bench it on its own.
+    pub part: Option<u8>,
+    /// The part whose answer is submitted.
+    pub submit: Option<u8>,
+}
+
+pub fn handle(year: Year, day: Day, options: &SolveOptions) {
+    let mut cmd_args = vec![
+        "run".to_string(),
+        "--bin".to_string(),
+        format!("{year}-{day}"),
+    ];
 
-    if dhat {
+    if options.dhat {
         cmd_args.extend([
             "--profile".to_string(),
             "dhat".to_string(),
             "--features".to_string(),
             "dhat-heap".to_string(),
         ]);
-    } else if release {
+    } else if options.release {
         cmd_args.push("--release".to_string());
     }
 
     cmd_args.push("--".to_string());
 
-    if let Some(submit_part) = submit_part {
+    if options.json {
+        cmd_args.push("--json".to_string());
+    }
+
+    if options.time {
+        cmd_args.push("--time".to_string());
+    }
+
+    if options.all_inputs {
+        cmd_args.push("--all-inputs".to_string());
+    }
+
+    if let Some(part) = options.part {
+        cmd_args.push("--part".to_string());
+        cmd_args.push(part.to_string());
+    }
+
+    if let Some(submit_part) = options.submit {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
     }
@@ -0,0 +1,16 @@
+use std::process;
+
+use crate::template::selector::DaySelector;
+use crate::template::summary::{print_summary, Summary};
+use crate::template::{Solution, Year};
+
+/// Print the summary of the selected days of a year from their stored answers, without running any solution.
+/// Every day is selected by default.
+pub fn handle(solutions: &[Solution], year: Year, selector: &DaySelector) {
+    let days = selector.select(solutions, year).unwrap_or_else(|e| {
+        eprintln!("Failed to select days: {e}");
+        process::exit(1);
+    });
+
+    print_summary(&Summary::from_answers(solutions, year).only(&days));
+}
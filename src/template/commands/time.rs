@@ -1,40 +1,88 @@
 use std::collections::HashSet;
+use std::process;
 
+use crate::template::compare::{compare, print_comparisons};
+use crate::template::history::{History, HistoryEntry};
+use crate::template::report::Format;
 use crate::template::run_multi::run_multi;
+use crate::template::selector::DaySelector;
 use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
-
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
-
-    let days_to_run = day.map_or_else(
-        || {
-            if run_all {
-                all_days().collect()
-            } else {
-                // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
-                    .collect()
-            }
+use crate::template::{all_days, readme_benchmarks, Day, MultiOptions, Solution, Year};
+
+/// Bench the selected days. Without a selection, days that are not fully benched run.
+/// With `compare`, the results are checked against the stored timings and the process exits with a non-zero status
+/// if any part regressed by more than the threshold.
+/// Formats other than text only print their report, the comparison is then reported by the exit status alone.
+pub fn handle(
+    solutions: &[Solution],
+    year: Year,
+    selector: &DaySelector,
+    run_all: bool,
+    store: bool,
+    compare_threshold: Option<f64>,
+    options: MultiOptions,
+) {
+    let stored_timings = Timings::read_from_file(year);
+
+    let days_to_run: HashSet<Day> = if !selector.is_empty() {
+        selector.select(solutions, year).unwrap_or_else(|e| {
+            eprintln!("Failed to select days: {e}");
+            process::exit(1);
+        })
+    } else if run_all {
+        all_days().collect()
+    } else if compare_threshold.is_some() {
+        // when comparing, rerun every day that has stored timings.
+        stored_timings.data.iter().map(|t| t.day).collect()
+    } else {
+        // when the `--all` flag is not set, filter out days that are fully benched.
+        all_days()
+            .filter(|day| !stored_timings.is_day_complete(*day))
+            .collect()
+    };
+
+    let is_text = options.format == Format::Text;
+
+    let timings = run_multi(
+        solutions,
+        year,
+        &days_to_run,
+        MultiOptions {
+            is_timed: true,
+            ..options
         },
-        |day| HashSet::from([day]),
-    );
+    )
+    .unwrap();
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let has_regressions = compare_threshold.is_some_and(|threshold| {
+        let comparisons = compare(&stored_timings, &timings);
+        if is_text {
+            print_comparisons(&comparisons, threshold);
+        }
+        comparisons.iter().any(|c| c.is_regression(threshold))
+    });
 
     if store {
+        if let Err(e) = History::append(year, &HistoryEntry::now(timings.clone())) {
+            eprintln!("Failed to append to benchmark history: {e}");
+        }
+
         let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        merged_timings.store_file(year).unwrap();
 
-        println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme_benchmarks::update(year, merged_timings) {
             Ok(()) => {
-                println!("Stored updated benchmarks.");
+                if is_text {
+                    println!("\nStored updated benchmarks.");
+                }
             }
             Err(_) => {
                 eprintln!("Failed to store updated benchmarks.");
             }
         }
     }
+
+    if has_regressions {
+        process::exit(1);
+    }
 }
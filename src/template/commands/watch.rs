@@ -0,0 +1,176 @@
+/// Rebuilds and reruns a day whenever its source or data files change.
+/// Files are polled for changes, which keeps the template free of platform-specific watcher dependencies.
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Stdio};
+use std::thread::sleep;
+use std::time::{Duration, SystemTime};
+use std::{fs, io};
+
+use crate::template::inputs::list_txt_files;
+use crate::template::{data_dir, Day, Year, ANSI_BOLD, ANSI_ITALIC, ANSI_RED, ANSI_RESET};
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// How long the watched files have to stay unchanged before a run starts, so that rapid saves cause a single run.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// The modification times of the watched files. Files that don't exist are absent.
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+/// Watch the files of a day and rerun its examples and inputs on every change, until interrupted.
+pub fn handle(year: Year, day: Day, release: bool) {
+    let mut last = snapshot(&watched_files(year, day));
+    run(year, day, release);
+
+    loop {
+        sleep(POLL_INTERVAL);
+
+        let mut current = snapshot(&watched_files(year, day));
+        if current == last {
+            continue;
+        }
+
+        loop {
+            sleep(DEBOUNCE);
+            let next = snapshot(&watched_files(year, day));
+            if next == current {
+                break;
+            }
+            current = next;
+        }
+
+        last = current;
+        run(year, day, release);
+    }
+}
+
+/// Build the day, then check its examples and every input against the known answers.
+fn run(year: Year, day: Day, release: bool) {
+    let bin = format!("{year}-{day}");
+    println!();
+    println!("{ANSI_BOLD}Running {bin}{ANSI_RESET}");
+
+    if cargo(&["build", "--quiet", "--bin", &bin], &[], release).is_ok_and(|s| s.success()) {
+        for (title, flag) in [("Examples", "--examples"), ("Inputs", "--all-inputs")] {
+            println!();
+            println!("{ANSI_BOLD}{title}{ANSI_RESET}");
+            if let Err(e) = cargo(&["run", "--quiet", "--bin", &bin], &[flag], release) {
+                eprintln!("failed to run {bin}: {e}");
+            }
+        }
+    } else {
+        println!("{ANSI_RED}Build failed.{ANSI_RESET}");
+    }
+
+    println!();
+    println!("{ANSI_ITALIC}Waiting for changes...{ANSI_RESET}");
+}
+
+fn cargo(args: &[&str], bin_args: &[&str], release: bool) -> io::Result<ExitStatus> {
+    let mut cmd = Command::new("cargo");
+    cmd.args(args);
+
+    if release {
+        cmd.arg("--release");
+    }
+
+    if !bin_args.is_empty() {
+        cmd.arg("--").args(bin_args);
+    }
+
+    cmd.stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status()
+}
+
+/// The solution of the day, the shared library and the day's examples, inputs and answers.
+fn watched_files(year: Year, day: Day) -> Vec<PathBuf> {
+    let mut files = vec![
+        PathBuf::from(format!("src/bin/{year}-{day}.rs")),
+        PathBuf::from("src/lib.rs"),
+    ];
+
+    for folder in ["examples", "inputs", "answers"] {
+        files.extend(day_files(&data_dir(year).join(folder), day));
+    }
+
+    files
+}
+
+/// The files of a day in a data folder: `NN.txt`, `NN.json`, `NN-*.txt` and every `.txt` file in `NN/`.
+fn day_files(dir: &Path, day: Day) -> Vec<PathBuf> {
+    let day = day.to_string();
+    let prefix = format!("{day}-");
+
+    let mut files: Vec<PathBuf> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_file())
+        .filter(|path| {
+            path.file_stem()
+                .map(|stem| stem.to_string_lossy())
+                .is_some_and(|stem| stem == day || stem.starts_with(&prefix))
+        })
+        .collect();
+
+    files.extend(list_txt_files(&dir.join(&day)).unwrap_or_default());
+    files.sort_unstable();
+    files
+}
+
+fn snapshot(files: &[PathBuf]) -> Snapshot {
+    files
+        .iter()
+        .filter_map(|path| {
+            let modified = fs::metadata(path).and_then(|m| m.modified()).ok()?;
+            Some((path.clone(), modified))
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs, process};
+
+    use super::{day_files, snapshot};
+    use crate::day;
+
+    #[test]
+    fn finds_files_of_a_day() {
+        let dir = env::temp_dir().join(format!("aoc-watch-{}", process::id()));
+        fs::create_dir_all(dir.join("06")).unwrap();
+        for name in [
+            "06.txt",
+            "06-2.txt",
+            "06.json",
+            "16.txt",
+            "07.txt",
+            "06/alice.txt",
+        ] {
+            fs::write(dir.join(name), "").unwrap();
+        }
+
+        let files = day_files(&dir, day!(6));
+        let before = snapshot(&files);
+        fs::remove_file(dir.join("06-2.txt")).unwrap();
+        let after = snapshot(&files);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            files,
+            vec![
+                dir.join("06/alice.txt"),
+                dir.join("06-2.txt"),
+                dir.join("06.json"),
+                dir.join("06.txt"),
+            ]
+        );
+        assert_eq!(before.len(), 4);
+        assert_eq!(after.len(), 3);
+    }
+}
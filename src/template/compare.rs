@@ -0,0 +1,197 @@
+/// Module that compares fresh benchmarks against stored timings to detect regressions.
+use std::time::Duration;
+
+use crate::template::stats::Stats;
+use crate::template::timings::Timings;
+use crate::template::{Day, ANSI_BOLD, ANSI_GREEN, ANSI_RED, ANSI_RESET};
+
+/// Regressions of more than this percentage fail a comparison unless `--threshold` is passed.
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// The median time of a single part before and after a change.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartComparison {
+    pub day: Day,
+    pub part: u8,
+    pub stored: Duration,
+    pub current: Duration,
+}
+
+impl PartComparison {
+    /// Relative change of the median time in percent. Positive values mean the part got slower.
+    pub fn change_percent(&self) -> f64 {
+        let stored = self.stored.as_nanos() as f64;
+        let current = self.current.as_nanos() as f64;
+
+        if stored == 0.0 {
+            return 0.0;
+        }
+
+        (current - stored) / stored * 100.0
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change_percent() > threshold
+    }
+
+    pub fn is_improvement(&self, threshold: f64) -> bool {
+        self.change_percent() < -threshold
+    }
+}
+
+/// Pair up every part that has both a stored and a current timing.
+pub fn compare(stored: &Timings, current: &Timings) -> Vec<PartComparison> {
+    let mut comparisons = vec![];
+
+    for timing in &current.data {
+        let Some(stored_timing) = stored.data.iter().find(|t| t.day == timing.day) else {
+            continue;
+        };
+
+        let parts: [(u8, Option<&Stats>, Option<&Stats>); 2] = [
+            (1, stored_timing.part_1.as_ref(), timing.part_1.as_ref()),
+            (2, stored_timing.part_2.as_ref(), timing.part_2.as_ref()),
+        ];
+
+        for (part, stored_stats, current_stats) in parts {
+            if let (Some(stored_stats), Some(current_stats)) = (stored_stats, current_stats) {
+                comparisons.push(PartComparison {
+                    day: timing.day,
+                    part,
+                    stored: stored_stats.median,
+                    current: current_stats.median,
+                });
+            }
+        }
+    }
+
+    comparisons.sort_unstable_by_key(|c| (c.day, c.part));
+    comparisons
+}
+
+/// Print a table of comparisons, highlighting regressions and improvements beyond `threshold`.
+pub fn print_comparisons(comparisons: &[PartComparison], threshold: f64) {
+    println!();
+    println!("{ANSI_BOLD}Comparison with stored timings{ANSI_RESET} (threshold: {threshold}%)");
+    println!("------");
+
+    if comparisons.is_empty() {
+        println!("No stored timings to compare against.");
+        return;
+    }
+
+    println!(
+        "Day  Part  {:>12}  {:>12}  {:>9}",
+        "Stored", "Current", "Change"
+    );
+
+    for comparison in comparisons {
+        let color = if comparison.is_regression(threshold) {
+            ANSI_RED
+        } else if comparison.is_improvement(threshold) {
+            ANSI_GREEN
+        } else {
+            ""
+        };
+
+        let change = format!("{:+.1}%", comparison.change_percent());
+
+        println!(
+            "{}   {}     {:>12}  {:>12}  {color}{change:>9}{ANSI_RESET}",
+            comparison.day,
+            comparison.part,
+            format!("{:.1?}", comparison.stored),
+            format!("{:.1?}", comparison.current),
+        );
+    }
+
+    let regressions = comparisons
+        .iter()
+        .filter(|c| c.is_regression(threshold))
+        .count();
+
+    println!();
+    if regressions == 0 {
+        println!("{ANSI_GREEN}No regressions.{ANSI_RESET}");
+    } else {
+        println!(
+            "{ANSI_RED}{regressions} part(s) regressed by more than {threshold}%.{ANSI_RESET}"
+        );
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{compare, PartComparison};
+    use crate::{
+        day,
+        template::{
+            stats::Stats,
+            timings::{Timing, Timings},
+        },
+    };
+
+    fn timing(day: u8, part_1: Option<u64>, part_2: Option<u64>) -> Timing {
+        let stats = |millis: Option<u64>| millis.map(|m| Stats::single(Duration::from_millis(m)));
+        Timing {
+            day: crate::template::Day::new(day).unwrap(),
+            parse: None,
+            part_1: stats(part_1),
+            part_2: stats(part_2),
+            total_nanos: 0.0,
+            exceeded: None,
+        }
+    }
+
+    #[test]
+    fn computes_change() {
+        let comparison = PartComparison {
+            day: day!(1),
+            part: 1,
+            stored: Duration::from_millis(10),
+            current: Duration::from_millis(12),
+        };
+
+        assert!((comparison.change_percent() - 20.0).abs() < 1e-9);
+        assert!(comparison.is_regression(10.0));
+        assert!(!comparison.is_regression(25.0));
+        assert!(!comparison.is_improvement(10.0));
+    }
+
+    #[test]
+    fn detects_improvements() {
+        let comparison = PartComparison {
+            day: day!(1),
+            part: 2,
+            stored: Duration::from_millis(10),
+            current: Duration::from_millis(5),
+        };
+
+        assert!(comparison.is_improvement(10.0));
+        assert!(!comparison.is_regression(10.0));
+    }
+
+    #[test]
+    fn pairs_up_parts_present_in_both() {
+        let stored = Timings {
+            data: vec![timing(1, Some(10), Some(20)), timing(2, Some(5), None)],
+        };
+        let current = Timings {
+            data: vec![
+                timing(1, Some(11), None),
+                timing(2, Some(4), Some(3)),
+                timing(3, Some(1), Some(1)),
+            ],
+        };
+
+        let comparisons = compare(&stored, &current);
+
+        assert_eq!(comparisons.len(), 2);
+        assert_eq!((comparisons[0].day, comparisons[0].part), (day!(1), 1));
+        assert_eq!((comparisons[1].day, comparisons[1].part), (day!(2), 1));
+        assert_eq!(comparisons[1].stored, Duration::from_millis(5));
+        assert_eq!(comparisons[1].current, Duration::from_millis(4));
+    }
+}
@@ -0,0 +1,270 @@
+/// Module that reads the examples of a day along with the answers they are expected to produce.
+/// An example file can start with a metadata header that is terminated by a `---` line:
+///
+/// ```text
+/// part_1: 41
+/// part_2: 6
+/// size: 7
+/// ---
+/// ....#.....
+/// ```
+///
+/// `part_1` and `part_2` are the expected answers, every other key is a parameter of the example.
+/// Besides `examples/NN.txt`, every `examples/NN-*.txt` and `examples/NN/*.txt` is an example of day `NN`.
+use std::{collections::HashMap, fs, io, path::Path, process, str::FromStr};
+
+use crate::template::inputs::list_txt_files;
+use crate::template::runner::RunOptions;
+use crate::template::{data_dir, Day, Solution, Year, ANSI_GREEN, ANSI_RED, ANSI_RESET};
+
+static HEADER_END: &str = "---";
+
+/// An example input and the answers it is expected to produce.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Example {
+    /// The file stem of the example, e.g. `06` or `06-2`.
+    pub name: String,
+    pub text: String,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+    pub params: HashMap<String, String>,
+}
+
+impl Example {
+    /// Parse an example file. Files without a metadata header are taken as-is.
+    pub fn parse(name: &str, contents: &str) -> Self {
+        let (header, text) = split_header(contents);
+
+        let mut example = Example {
+            name: name.to_string(),
+            text: text.to_string(),
+            ..Example::default()
+        };
+
+        for (key, value) in header.iter().filter_map(|line| line.split_once(':')) {
+            let value = value.trim();
+            if value.is_empty() {
+                continue;
+            }
+
+            match key.trim() {
+                "part_1" => example.part_1 = Some(value.to_string()),
+                "part_2" => example.part_2 = Some(value.to_string()),
+                key => {
+                    example.params.insert(key.to_string(), value.to_string());
+                }
+            }
+        }
+
+        example
+    }
+
+    /// Format the example as a file, with a metadata header that lists its answers and parameters.
+    pub fn to_file(&self) -> String {
+        let mut params: Vec<_> = self.params.iter().collect();
+        params.sort_unstable();
+
+        let mut contents = format!(
+            "part_1:{}\npart_2:{}\n",
+            format_value(self.part_1.as_deref()),
+            format_value(self.part_2.as_deref())
+        );
+
+        for (key, value) in params {
+            contents.push_str(&format!("{key}: {value}\n"));
+        }
+
+        contents.push_str(HEADER_END);
+        contents.push('\n');
+        contents.push_str(&self.text);
+        contents
+    }
+
+    /// The expected answer of a part, if the example declares one.
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Parse a parameter of the example, e.g. `example.param::<usize>("size")`.
+    pub fn param<T: FromStr>(&self, key: &str) -> Option<T> {
+        self.params.get(key)?.parse().ok()
+    }
+}
+
+/// Split an example file into the lines of its metadata header and the example text.
+/// The header is only recognized if every line before the first `---` is a `key: value` pair, and there is at least one.
+pub fn split_header(contents: &str) -> (Vec<&str>, &str) {
+    let mut offset = 0;
+    let mut header = vec![];
+
+    for line in contents.split_inclusive('\n') {
+        let trimmed = line.trim_end();
+        offset += line.len();
+
+        if trimmed == HEADER_END && !header.is_empty() {
+            return (header, &contents[offset..]);
+        }
+
+        if !is_header_line(trimmed) {
+            break;
+        }
+
+        header.push(trimmed);
+    }
+
+    (vec![], contents)
+}
+
+fn format_value(value: Option<&str>) -> String {
+    value.map_or_else(String::new, |value| format!(" {value}"))
+}
+
+fn is_header_line(line: &str) -> bool {
+    line.split_once(':').is_some_and(|(key, _)| {
+        !key.is_empty()
+            && key
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    })
+}
+
+/// Read every example of a day, sorted by name.
+pub fn read_examples(year: Year, day: Day) -> io::Result<Vec<Example>> {
+    let dir = data_dir(year).join("examples");
+
+    let mut paths: Vec<_> = list_txt_files(&dir)?
+        .into_iter()
+        .filter(|path| {
+            let stem = file_stem(path);
+            stem == day.to_string() || stem.starts_with(&format!("{day}-"))
+        })
+        .collect();
+
+    paths.extend(list_txt_files(&dir.join(day.to_string()))?);
+
+    paths
+        .iter()
+        .map(|path| Ok(Example::parse(&file_stem(path), &fs::read_to_string(path)?)))
+        .collect()
+}
+
+fn file_stem(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+/// Run every part against every example that declares an expected answer for it.
+/// Panics with a list of mismatches if any part produced a different answer.
+pub fn check_examples(solutions: &[Solution]) {
+    let failures: Vec<String> = solutions.iter().flat_map(check_solution).collect();
+
+    assert!(
+        failures.is_empty(),
+        "{} example(s) failed:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+/// Check the examples of a single solution, as done by `cargo solve --watch`.
+/// Exits with a non-zero status if any part produced a different answer.
+pub fn run_examples(solution: &Solution) {
+    if !check_solution(solution).is_empty() {
+        process::exit(1);
+    }
+}
+
+/// Print a line per checked part and return the mismatches.
+fn check_solution(solution: &Solution) -> Vec<String> {
+    let examples = read_examples(solution.year, solution.day).unwrap_or_else(|e| {
+        panic!(
+            "could not read examples of {}-{}: {e}",
+            solution.year, solution.day
+        )
+    });
+
+    let mut failures = vec![];
+
+    for example in &examples {
+        for &part in solution.parts {
+            let Some(expected) = example.expected(part) else {
+                continue;
+            };
+
+            let options = RunOptions {
+                is_quiet: true,
+                part: Some(part),
+                ..RunOptions::default()
+            };
+
+            let results = (solution.run)(&example.text, &example.params, options);
+            // the error of a malformed example can come from the parse step instead of the part.
+            let error = results.iter().find_map(|result| result.error.as_deref());
+            let answer = results
+                .iter()
+                .find(|result| result.part == part)
+                .and_then(|result| result.answer.clone())
+                .or_else(|| error.map(|e| format!("invalid input ({e})")))
+                .unwrap_or_else(|| "None".into());
+            let label = format!(
+                "{}-{} part {part} ({}.txt)",
+                solution.year, solution.day, example.name
+            );
+
+            if answer == expected {
+                println!("{label}: {ANSI_GREEN}✔{ANSI_RESET}");
+            } else {
+                println!("{label}: {ANSI_RED}✘{ANSI_RESET} expected {expected}, got {answer}");
+                failures.push(format!("{label}: expected {expected}, got {answer}"));
+            }
+        }
+    }
+
+    failures
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{split_header, Example};
+
+    #[test]
+    fn parses_headers() {
+        let example = Example::parse("14", "part_1: 12\npart_2:\nwidth: 11\n---\np=0,4 v=3,-3\n");
+
+        assert_eq!(example.text, "p=0,4 v=3,-3\n");
+        assert_eq!(example.expected(1), Some("12"));
+        assert_eq!(example.expected(2), None);
+        assert_eq!(example.param::<usize>("width"), Some(11));
+        assert_eq!(example.param::<usize>("height"), None);
+    }
+
+    #[test]
+    fn formats_files() {
+        let contents = "part_1: 12\npart_2:\nheight: 7\nwidth: 11\n---\np=0,4 v=3,-3\n";
+        assert_eq!(Example::parse("14", contents).to_file(), contents);
+        assert_eq!(Example::default().to_file(), "part_1:\npart_2:\n---\n");
+    }
+
+    #[test]
+    fn keeps_files_without_headers() {
+        let grid = "#.#\n---\n...\n";
+        assert_eq!(split_header(grid), (vec![], grid));
+
+        let text = "a: b\nc: d\n";
+        assert_eq!(split_header(text), (vec![], text));
+    }
+
+    #[test]
+    fn handles_crlf_headers() {
+        let (header, text) = split_header("part_1: 3\r\n---\r\n1 2\r\n");
+        assert_eq!(header, vec!["part_1: 3"]);
+        assert_eq!(text, "1 2\r\n");
+    }
+}
@@ -0,0 +1,346 @@
+/// Module that keeps an append-only history of stored benchmarks.
+/// Every line of the history file is a JSON document holding the timings of one `cargo time --store` run.
+use std::{
+    collections::HashMap,
+    fs::{self, OpenOptions},
+    io::{Error, Write},
+    path::PathBuf,
+    process::Command,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::stats::Stats;
+use crate::template::timings::{Timing, Timings};
+use crate::template::{data_dir, Day, Year, ANSI_BOLD, ANSI_GREEN, ANSI_RED, ANSI_RESET};
+
+/// The timings of a single stored run.
+#[derive(Clone, Debug)]
+pub struct HistoryEntry {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// Abbreviated hash of the commit that was checked out, suffixed with `-dirty` for uncommitted changes.
+    pub commit: Option<String>,
+    pub timings: Timings,
+}
+
+impl HistoryEntry {
+    /// Create an entry for timings that were just measured.
+    pub fn now(timings: Timings) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        Self {
+            timestamp,
+            commit: current_commit(),
+            timings,
+        }
+    }
+}
+
+/// All stored runs, oldest first.
+#[derive(Clone, Debug, Default)]
+pub struct History {
+    pub entries: Vec<HistoryEntry>,
+}
+
+impl History {
+    /// Append an entry to the history file, creating it if needed.
+    pub fn append(year: Year, entry: &HistoryEntry) -> Result<(), Error> {
+        let line = JsonValue::from(entry)
+            .stringify()
+            .map_err(|e| Error::other(e.to_string()))?;
+
+        fs::create_dir_all(data_dir(year))?;
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(get_path(year))?;
+
+        writeln!(file, "{line}")
+    }
+
+    /// Rehydrate the history file. If not present, returns an empty history.
+    pub fn read_from_file(year: Year) -> Self {
+        match fs::read_to_string(get_path(year)) {
+            Ok(s) => History::from(s.as_str()),
+            Err(_) => History::default(),
+        }
+    }
+
+    /// The timings of a day in every run that benched it, oldest first.
+    pub fn for_day(&self, day: Day) -> Vec<(&HistoryEntry, &Timing)> {
+        self.entries
+            .iter()
+            .filter_map(|entry| {
+                let timing = entry.timings.data.iter().find(|t| t.day == day)?;
+                Some((entry, timing))
+            })
+            .collect()
+    }
+}
+
+fn get_path(year: Year) -> PathBuf {
+    data_dir(year).join("timings_history.jsonl")
+}
+
+/// Print how the parts of a day evolved across stored runs.
+pub fn print_history(history: &History, day: Day) {
+    let runs = history.for_day(day);
+
+    println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    println!("------");
+
+    if runs.is_empty() {
+        println!("No stored timings. Run `cargo time {day} --store` to record one.");
+        return;
+    }
+
+    println!(
+        "{:<16}  {:<12}  {:>22}  {:>22}",
+        "Date", "Commit", "Part 1", "Part 2"
+    );
+
+    let mut previous: [Option<&Stats>; 2] = [None, None];
+
+    for (entry, timing) in runs {
+        let current = [timing.part_1.as_ref(), timing.part_2.as_ref()];
+
+        let cells = [0, 1].map(|i| format_cell(previous[i], current[i]));
+
+        println!(
+            "{:<16}  {:<12}  {}  {}",
+            format_timestamp(entry.timestamp),
+            entry.commit.as_deref().unwrap_or("-"),
+            cells[0],
+            cells[1],
+        );
+
+        for i in 0..2 {
+            if current[i].is_some() {
+                previous[i] = current[i];
+            }
+        }
+    }
+}
+
+/// Format the median of a part along with its change relative to the previous run.
+fn format_cell(previous: Option<&Stats>, current: Option<&Stats>) -> String {
+    let Some(current) = current else {
+        return format!("{:>22}", "-");
+    };
+
+    let median = format!("{:.1?}", current.median);
+
+    let Some(previous) = previous.filter(|p| !p.median.is_zero()) else {
+        return format!("{median:>22}");
+    };
+
+    let change = (current.median.as_nanos() as f64 - previous.median.as_nanos() as f64)
+        / previous.median.as_nanos() as f64
+        * 100.0;
+
+    let color = if change > 0.0 { ANSI_RED } else { ANSI_GREEN };
+    let change = format!("({change:+.1}%)");
+
+    format!("{median:>12} {color}{change:>9}{ANSI_RESET}")
+}
+
+/// Format seconds since the unix epoch as a UTC date, e.g. `2024-12-16 08:05`.
+fn format_timestamp(timestamp: u64) -> String {
+    let days = timestamp / 86_400;
+    let seconds = timestamp % 86_400;
+
+    // see: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}",
+        seconds / 3600,
+        seconds % 3600 / 60
+    )
+}
+
+/// The commit that is checked out, if git is available.
+fn current_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let hash = String::from_utf8(output.stdout).ok()?.trim().to_string();
+
+    let is_dirty = Command::new("git")
+        .args(["status", "--porcelain", "--untracked-files=no"])
+        .output()
+        .is_ok_and(|output| !output.stdout.is_empty());
+
+    Some(if is_dirty {
+        format!("{hash}-dirty")
+    } else {
+        hash
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&HistoryEntry> for JsonValue {
+    fn from(value: &HistoryEntry) -> Self {
+        let mut map = match JsonValue::from(value.timings.clone()) {
+            JsonValue::Object(map) => map,
+            _ => HashMap::new(),
+        };
+
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert(
+            "commit".into(),
+            match &value.commit {
+                Some(commit) => JsonValue::String(commit.clone()),
+                None => JsonValue::Null,
+            },
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HistoryEntry {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history entry to be a JSON object.")?;
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected entry.timestamp to be a number.")?;
+
+        let commit = json
+            .get("commit")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected entry.commit to be null or string.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(HistoryEntry {
+            timestamp: timestamp as u64,
+            commit: commit.cloned(),
+            timings: Timings::try_from(value)?,
+        })
+    }
+}
+
+impl From<&str> for History {
+    /// Parse the lines of a history file. Malformed lines are reported and skipped.
+    fn from(value: &str) -> Self {
+        let entries = value
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .filter_map(|line| {
+                let entry = JsonValue::from_str(line)
+                    .map_err(|e| e.to_string())
+                    .and_then(|json| HistoryEntry::try_from(&json));
+
+                match entry {
+                    Ok(entry) => Some(entry),
+                    Err(e) => {
+                        eprintln!("Skipping malformed history entry: {e}");
+                        None
+                    }
+                }
+            })
+            .collect();
+
+        History { entries }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::{format_timestamp, History, HistoryEntry};
+    use crate::{
+        day,
+        template::{
+            stats::Stats,
+            timings::{Timing, Timings},
+        },
+    };
+
+    fn entry(timestamp: u64, day: u8, millis: u64) -> HistoryEntry {
+        HistoryEntry {
+            timestamp,
+            commit: Some(format!("abc{timestamp}")),
+            timings: Timings {
+                data: vec![Timing {
+                    day: crate::template::Day::new(day).unwrap(),
+                    parse: None,
+                    part_1: Some(Stats::single(Duration::from_millis(millis))),
+                    part_2: None,
+                    total_nanos: (millis * 1_000_000) as f64,
+                    exceeded: None,
+                }],
+            },
+        }
+    }
+
+    #[test]
+    fn round_trips_entries() {
+        let lines = [entry(1, 16, 10), entry(2, 3, 1), entry(3, 16, 12)]
+            .iter()
+            .map(|e| JsonValue::from(e).stringify().unwrap())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let history = History::from(lines.as_str());
+        assert_eq!(history.entries.len(), 3);
+        assert_eq!(history.entries[2].commit.as_deref(), Some("abc3"));
+
+        let day_16 = history.for_day(day!(16));
+        assert_eq!(day_16.len(), 2);
+        assert_eq!(day_16[0].0.timestamp, 1);
+        assert_eq!(
+            day_16[1].1.part_1.unwrap().median,
+            Duration::from_millis(12)
+        );
+    }
+
+    #[test]
+    fn skips_malformed_lines() {
+        let line = JsonValue::from(&entry(1, 1, 1)).stringify().unwrap();
+        let history = History::from(format!("{{}}\nnot json\n\n{line}").as_str());
+        assert_eq!(history.entries.len(), 1);
+    }
+
+    #[test]
+    fn formats_timestamps() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00");
+        assert_eq!(format_timestamp(1_734_336_300), "2024-12-16 08:05");
+        assert_eq!(format_timestamp(951_782_400), "2000-02-29 00:00");
+    }
+}
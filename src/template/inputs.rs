@@ -0,0 +1,104 @@
+/// Module that locates the puzzle inputs of a day.
+/// Besides `data/YYYY/inputs/NN.txt`, a day can have several inputs in `data/YYYY/inputs/NN/*.txt`,
+/// e.g. one per account. Each of those can have expected answers in `data/YYYY/answers/NN/<name>.json`.
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::template::answers::Answers;
+use crate::template::{data_dir, Day, Year};
+
+/// A named puzzle input along with its known-good answers.
+#[derive(Clone, Debug)]
+pub struct Input {
+    /// The file stem of the input, e.g. `alice` for `inputs/01/alice.txt` or `01` for `inputs/01.txt`.
+    pub name: String,
+    pub text: String,
+    pub answers: Answers,
+}
+
+/// Read every input of a day, starting with `inputs/NN.txt` if present.
+/// Returns an error if the day has no input at all.
+pub fn read_inputs(year: Year, day: Day) -> io::Result<Vec<Input>> {
+    let dir = data_dir(year).join("inputs");
+    let mut inputs = vec![];
+
+    let single = dir.join(format!("{day}.txt"));
+    if single.is_file() {
+        inputs.push(Input {
+            name: day.to_string(),
+            text: fs::read_to_string(single)?,
+            answers: Answers::read_from_file(year, day),
+        });
+    }
+
+    for path in list_txt_files(&dir.join(day.to_string()))? {
+        let name = file_stem(&path);
+        inputs.push(Input {
+            answers: Answers::read_for_input(year, day, &name),
+            text: fs::read_to_string(&path)?,
+            name,
+        });
+    }
+
+    if inputs.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("no input found for day {day}."),
+        ));
+    }
+
+    Ok(inputs)
+}
+
+/// List the `.txt` files of a directory, sorted by name. A missing directory has no files.
+pub(crate) fn list_txt_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e),
+    };
+
+    let mut files = entries
+        .map(|entry| entry.map(|e| e.path()))
+        .filter(|path| {
+            path.as_ref().map_or(true, |p| {
+                p.is_file() && p.extension().is_some_and(|e| e == "txt")
+            })
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+
+    files.sort_unstable();
+    Ok(files)
+}
+
+fn file_stem(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs, process};
+
+    use super::list_txt_files;
+
+    #[test]
+    fn lists_txt_files_by_name() {
+        let dir = env::temp_dir().join(format!("aoc-inputs-{}", process::id()));
+        fs::create_dir_all(dir.join("nested.txt")).unwrap();
+        for name in ["bob.txt", "alice.txt", "notes.md"] {
+            fs::write(dir.join(name), "").unwrap();
+        }
+
+        let files = list_txt_files(&dir).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(files, vec![dir.join("alice.txt"), dir.join("bob.txt")]);
+        assert!(list_txt_files(&dir).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,277 @@
+/// Module that defines the limits each part of a multi-day run has to stay within, see [`run_multi`](super::run_multi).
+/// Limits can be set for every part, or for a single day or part. A part that exceeds a limit is killed along with
+/// the rest of its day, and reported as `TIMEOUT` or `OOM`.
+use std::{fmt::Display, fs, str::FromStr, time::Duration};
+
+use super::Day;
+
+/// A limit that a part exceeded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Limit {
+    Timeout,
+    Memory,
+}
+
+/// The days or parts a limit applies to. A limit of a more specific scope takes precedence.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Scope {
+    All,
+    Day(Day),
+    /// A single part of a day. The parse step of a day is only limited by the scopes of the day.
+    Part(Day, u8),
+}
+
+impl Scope {
+    fn contains(self, day: Day, part: u8) -> bool {
+        match self {
+            Scope::All => true,
+            Scope::Day(d) => d == day,
+            Scope::Part(d, p) => d == day && p == part,
+        }
+    }
+
+    fn specificity(self) -> u8 {
+        match self {
+            Scope::All => 0,
+            Scope::Day(_) => 1,
+            Scope::Part(..) => 2,
+        }
+    }
+}
+
+/// The value of a `--timeout <seconds>` or `--max-memory <MiB>` flag, where `0` means unlimited.
+/// Either a value for every part like `30`, or for a day or a single part like `12=30` or `6.2=30`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LimitFlag {
+    pub scope: Scope,
+    pub value: f64,
+}
+
+/// The limits of each part of a multi-day run, see [`Limits::for_part`]. Unlimited by default.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Limits {
+    timeouts: Vec<(Scope, Option<Duration>)>,
+    memory: Vec<(Scope, Option<u64>)>,
+}
+
+impl Limits {
+    /// Build limits from the `--timeout` and `--max-memory` flags. Of the flags with the same scope, the last one wins.
+    pub fn from_flags(timeouts: &[LimitFlag], memory: &[LimitFlag]) -> Self {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Self {
+            timeouts: timeouts
+                .iter()
+                .map(|flag| {
+                    let timeout = (flag.value > 0.0).then(|| Duration::from_secs_f64(flag.value));
+                    (flag.scope, timeout)
+                })
+                .collect(),
+            memory: memory
+                .iter()
+                .map(|flag| {
+                    let bytes = (flag.value > 0.0).then_some((flag.value * 1024.0 * 1024.0) as u64);
+                    (flag.scope, bytes)
+                })
+                .collect(),
+        }
+    }
+
+    /// Whether no limit is set for any part, in which case days run in-process.
+    pub fn is_unlimited(&self) -> bool {
+        self.timeouts.iter().all(|(_, timeout)| timeout.is_none())
+            && self.memory.iter().all(|(_, bytes)| bytes.is_none())
+    }
+
+    /// The limits of a part of a day, or of its parse step if `part` is [`PARSE_PART`](super::runner::PARSE_PART).
+    pub fn for_part(&self, day: Day, part: u8) -> PartLimits {
+        PartLimits {
+            timeout: resolve(&self.timeouts, day, part),
+            memory: resolve(&self.memory, day, part),
+        }
+    }
+}
+
+/// The value of the most specific scope that contains the part, or `None` if no scope does.
+fn resolve<T: Copy>(values: &[(Scope, Option<T>)], day: Day, part: u8) -> Option<T> {
+    values
+        .iter()
+        .filter(|(scope, _)| scope.contains(day, part))
+        // `max_by_key` returns the last of equal elements, so later flags override earlier ones.
+        .max_by_key(|(scope, _)| scope.specificity())
+        .and_then(|&(_, value)| value)
+}
+
+/// The limits of a single part. `None` means unlimited.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PartLimits {
+    /// The wall-clock time the part may run, including benching.
+    pub timeout: Option<Duration>,
+    /// The memory the day may use while running the part, in bytes. Only enforced on Linux.
+    pub memory: Option<u64>,
+}
+
+impl PartLimits {
+    /// Describe the limit that was exceeded, e.g. `exceeded 300s`.
+    pub fn describe(&self, limit: Limit) -> String {
+        match limit {
+            Limit::Timeout => format!("exceeded {:?}", self.timeout.unwrap_or_default()),
+            Limit::Memory => format!(
+                "exceeded {} MiB",
+                self.memory.unwrap_or_default() / 1024 / 1024
+            ),
+        }
+    }
+}
+
+/// The resident memory of a process in bytes, or `None` if it can't be read, e.g. on other platforms than Linux.
+pub fn memory_usage(pid: u32) -> Option<u64> {
+    let status = fs::read_to_string(format!("/proc/{pid}/status")).ok()?;
+
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmRSS:"))
+        .and_then(|value| value.trim().strip_suffix("kB"))
+        .and_then(|kib| kib.trim().parse::<u64>().ok())
+        .map(|kib| kib * 1024)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl Display for Limit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Limit::Timeout => "TIMEOUT",
+            Limit::Memory => "OOM",
+        })
+    }
+}
+
+impl FromStr for Limit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "TIMEOUT" => Ok(Limit::Timeout),
+            "OOM" => Ok(Limit::Memory),
+            _ => Err(format!("unknown limit `{s}`.")),
+        }
+    }
+}
+
+impl FromStr for LimitFlag {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (scope, value) = match s.split_once('=') {
+            None => (Scope::All, s),
+            Some((scope, value)) => {
+                let scope = match scope.split_once('.') {
+                    None => Scope::Day(
+                        scope
+                            .parse()
+                            .map_err(|_| format!("invalid day `{scope}`."))?,
+                    ),
+                    Some((day, part)) => Scope::Part(
+                        day.parse().map_err(|_| format!("invalid day `{day}`."))?,
+                        part.parse()
+                            .ok()
+                            .filter(|part| matches!(part, 1 | 2))
+                            .ok_or(format!("invalid part `{part}`, expected 1 or 2."))?,
+                    ),
+                };
+                (scope, value)
+            }
+        };
+
+        Ok(LimitFlag {
+            scope,
+            value: value
+                .parse()
+                .ok()
+                .filter(|&value: &f64| value >= 0.0)
+                .ok_or(format!("invalid limit `{value}`."))?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{process, time::Duration};
+
+    use crate::day;
+    use crate::template::runner::PARSE_PART;
+
+    use super::{memory_usage, LimitFlag, Limits, PartLimits, Scope};
+
+    fn flags(values: &[&str]) -> Vec<LimitFlag> {
+        values.iter().map(|value| value.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn parses_flags() {
+        assert_eq!(
+            "1.5".parse::<LimitFlag>(),
+            Ok(LimitFlag {
+                scope: Scope::All,
+                value: 1.5
+            })
+        );
+        assert_eq!(
+            "12=30".parse::<LimitFlag>(),
+            Ok(LimitFlag {
+                scope: Scope::Day(day!(12)),
+                value: 30.0
+            })
+        );
+        assert_eq!(
+            "6.2=10".parse::<LimitFlag>(),
+            Ok(LimitFlag {
+                scope: Scope::Part(day!(6), 2),
+                value: 10.0
+            })
+        );
+        assert!("6.3=10".parse::<LimitFlag>().is_err());
+        assert!("26=10".parse::<LimitFlag>().is_err());
+        assert!("12=-1".parse::<LimitFlag>().is_err());
+    }
+
+    #[test]
+    fn builds_limits_from_flags() {
+        assert_eq!(Limits::from_flags(&[], &[]), Limits::default());
+        assert!(Limits::default().is_unlimited());
+
+        let limits = Limits::from_flags(&flags(&["1.5"]), &flags(&["512"]));
+        assert_eq!(
+            limits.for_part(day!(1), 1),
+            PartLimits {
+                timeout: Some(Duration::from_millis(1500)),
+                memory: Some(512 * 1024 * 1024),
+            }
+        );
+
+        assert!(Limits::from_flags(&flags(&["0"]), &flags(&["0"])).is_unlimited());
+        assert!(!Limits::from_flags(&flags(&["12=5"]), &[]).is_unlimited());
+    }
+
+    #[test]
+    fn resolves_the_most_specific_limit() {
+        let limits = Limits::from_flags(&flags(&["6.2=10", "5", "6=20", "7=0", "6=30"]), &[]);
+        let timeout = |day, part| limits.for_part(day, part).timeout;
+
+        assert_eq!(timeout(day!(1), 1), Some(Duration::from_secs(5)));
+        assert_eq!(timeout(day!(6), PARSE_PART), Some(Duration::from_secs(30)));
+        assert_eq!(timeout(day!(6), 1), Some(Duration::from_secs(30)));
+        assert_eq!(timeout(day!(6), 2), Some(Duration::from_secs(10)));
+        assert_eq!(timeout(day!(7), 1), None);
+    }
+
+    #[test]
+    fn reads_memory_usage() {
+        if cfg!(target_os = "linux") {
+            assert!(memory_usage(process::id()).is_some_and(|bytes| bytes > 0));
+        }
+        assert_eq!(memory_usage(u32::MAX), None);
+    }
+}
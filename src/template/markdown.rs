@@ -0,0 +1,386 @@
+//! Module that converts the HTML served by Advent of Code to Markdown.
+//! Only the small subset of HTML that is used in puzzle descriptions is supported.
+
+/// Extract the puzzle descriptions (`<article>` elements) of a page and convert them to Markdown.
+/// Returns the whole page converted if there is no article, e.g. for short responses.
+pub fn puzzle_to_markdown(html: &str) -> String {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(end) = rest[start..].find("</article>") else {
+            break;
+        };
+        articles.push(&rest[start..start + end + "</article>".len()]);
+        rest = &rest[start + end + "</article>".len()..];
+    }
+
+    if articles.is_empty() {
+        return html_to_markdown(html);
+    }
+
+    articles
+        .into_iter()
+        .map(html_to_markdown)
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Convert a fragment of HTML to Markdown.
+pub fn html_to_markdown(html: &str) -> String {
+    let html = hoist_emphasis(html);
+
+    let mut out = String::new();
+    let mut in_pre = false;
+    let mut links: Vec<String> = vec![];
+
+    for token in tokenize(&html) {
+        match token {
+            Token::Text(text) => {
+                let text = decode_entities(text);
+                if in_pre {
+                    out.push_str(&text);
+                } else {
+                    push_inline(&mut out, &text);
+                }
+            }
+            Token::Open(name, attrs) => match name {
+                "p" | "article" | "main" | "ul" => start_block(&mut out),
+                "h2" => {
+                    start_block(&mut out);
+                    out.push_str("## ");
+                }
+                "pre" => {
+                    start_block(&mut out);
+                    out.push_str("```\n");
+                    in_pre = true;
+                }
+                "li" => {
+                    trim_trailing_spaces(&mut out);
+                    if !out.is_empty() && !out.ends_with('\n') {
+                        out.push('\n');
+                    }
+                    out.push_str("- ");
+                }
+                "br" => out.push('\n'),
+                "code" if !in_pre => out.push('`'),
+                "em" if !in_pre => out.push('*'),
+                "a" => {
+                    links.push(get_attribute(attrs, "href").unwrap_or_default());
+                    out.push('[');
+                }
+                _ => {}
+            },
+            Token::Close(name) => match name {
+                "p" | "h2" | "article" | "main" | "ul" => start_block(&mut out),
+                "pre" => {
+                    if !out.ends_with('\n') {
+                        out.push('\n');
+                    }
+                    out.push_str("```");
+                    in_pre = false;
+                    start_block(&mut out);
+                }
+                "code" if !in_pre => out.push('`'),
+                "em" if !in_pre => out.push('*'),
+                "a" => {
+                    let href = links.pop().unwrap_or_default();
+                    out.push_str(&format!("]({href})"));
+                }
+                _ => {}
+            },
+        }
+    }
+
+    out.trim().to_string()
+}
+
+/// Parse the title from the first heading of a puzzle, e.g. `## --- Day 6: Guard Gallivant ---`.
+pub fn puzzle_title(markdown: &str) -> Option<String> {
+    let heading = markdown.lines().find_map(|line| line.strip_prefix("## "))?;
+    let title = heading.trim().trim_matches('-').trim();
+    (!title.is_empty()).then(|| title.to_string())
+}
+
+/// The contents of the first code block of a puzzle, which is the example in most puzzles.
+pub fn first_code_block(markdown: &str) -> Option<String> {
+    let start = markdown.find("```\n")? + "```\n".len();
+    let end = markdown[start..].find("\n```")?;
+    Some(format!("{}\n", &markdown[start..start + end]))
+}
+
+/// The answer of the example of the first part, if it can be detected.
+/// Puzzles state it as the last emphasized code span after the example, e.g. `*`41`*`.
+pub fn example_answer(markdown: &str) -> Option<String> {
+    let start = markdown.find("```\n")? + "```\n".len();
+    let rest = &markdown[start..];
+    let rest = &rest[rest.find("\n```")? + "\n```".len()..];
+    let part_one = rest.find("\n## ").map_or(rest, |end| &rest[..end]);
+
+    let mut answer = None;
+    let mut rest = part_one;
+
+    while let Some(start) = rest.find("*`") {
+        let inner = &rest[start + "*`".len()..];
+        let Some(end) = inner.find("`*") else {
+            break;
+        };
+        answer = Some(inner[..end].to_string());
+        rest = &inner[end + "`*".len()..];
+    }
+
+    answer
+}
+
+/// Emphasis can't be expressed inside a code span, so turn `<code><em>42</em></code>` into
+/// `<em><code>42</code></em>`. Code that is only partially emphasized is left alone.
+fn hoist_emphasis(html: &str) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<code><em>") {
+        out.push_str(&rest[..start]);
+        let inner = &rest[start + "<code><em>".len()..];
+
+        match inner.find("</em></code>") {
+            Some(end) if !inner[..end].contains('<') => {
+                out.push_str(&format!("<em><code>{}</code></em>", &inner[..end]));
+                rest = &inner[end + "</em></code>".len()..];
+            }
+            _ => {
+                out.push_str("<code><em>");
+                rest = inner;
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+enum Token<'a> {
+    Open(&'a str, &'a str),
+    Close(&'a str),
+    Text(&'a str),
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+
+        rest = &rest[start..];
+
+        if rest.starts_with("<!--") {
+            rest = rest.find("-->").map_or("", |end| &rest[end + 3..]);
+            continue;
+        }
+
+        let Some(end) = rest.find('>') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+
+        let tag = rest[1..end].trim_end_matches('/').trim();
+        rest = &rest[end + 1..];
+
+        if let Some(name) = tag.strip_prefix('/') {
+            tokens.push(Token::Close(name.trim()));
+        } else if !tag.starts_with('!') {
+            let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+            tokens.push(Token::Open(name, attrs));
+        }
+    }
+
+    tokens
+}
+
+fn get_attribute(attrs: &str, name: &str) -> Option<String> {
+    let start = attrs.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = attrs[start..].find('"')?;
+    Some(decode_entities(&attrs[start..start + end]))
+}
+
+/// Append text outside of preformatted blocks, collapsing whitespace like a browser would.
+fn push_inline(out: &mut String, text: &str) {
+    for (i, word) in text.split(char::is_whitespace).enumerate() {
+        if i > 0 && !out.is_empty() && !out.ends_with([' ', '\n']) {
+            out.push(' ');
+        }
+        out.push_str(word);
+    }
+}
+
+fn trim_trailing_spaces(out: &mut String) {
+    out.truncate(out.trim_end_matches(' ').len());
+}
+
+/// Separate the next block from the previous one with a blank line.
+fn start_block(out: &mut String) {
+    trim_trailing_spaces(out);
+    if out.is_empty() {
+        return;
+    }
+    while !out.ends_with("\n\n") {
+        out.push('\n');
+    }
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let decoded = rest.find(';').and_then(|end| {
+            let entity = &rest[1..end];
+            let ch = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                _ => entity
+                    .strip_prefix("#x")
+                    .map(|hex| u32::from_str_radix(hex, 16))
+                    .or_else(|| entity.strip_prefix('#').map(str::parse))
+                    .and_then(Result::ok)
+                    .and_then(char::from_u32),
+            }?;
+            Some((ch, end))
+        });
+
+        match decoded {
+            Some((ch, end)) => {
+                out.push(ch);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        decode_entities, example_answer, first_code_block, html_to_markdown, puzzle_title,
+        puzzle_to_markdown,
+    };
+
+    #[test]
+    fn converts_puzzle_descriptions() {
+        let html = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2><p>The <em>Chief Historian</em> is
+always present.</p>
+<p>For example:</p>
+<pre><code>3   4
+4   <em>3</em>
+</code></pre>
+<ul>
+<li>The smallest number in the <a href="/2024/day/1/input" target="_blank">left list</a> is <code>1</code>.</li>
+<li>Then <code>2</code>.</li>
+</ul>
+<p>In the example above, this is <code>2 + 1 = <em>11</em></code> or <code><em>11</em></code>!</p>
+</article>
+<p>Your puzzle answer was <code>42</code>.</p>
+</main></body></html>"#;
+
+        let expected = [
+            "## --- Day 1: Historian Hysteria ---",
+            "",
+            "The *Chief Historian* is always present.",
+            "",
+            "For example:",
+            "",
+            "```",
+            "3   4",
+            "4   3",
+            "```",
+            "",
+            "- The smallest number in the [left list](/2024/day/1/input) is `1`.",
+            "- Then `2`.",
+            "",
+            "In the example above, this is `2 + 1 = *11*` or *`11`*!",
+        ]
+        .join("\n");
+
+        assert_eq!(puzzle_to_markdown(html), expected);
+    }
+
+    #[test]
+    fn extracts_examples() {
+        let markdown = [
+            "## --- Day 6: Guard Gallivant ---",
+            "",
+            "The guard is *`^`*. For example:",
+            "",
+            "```",
+            "....#",
+            "..^..",
+            "```",
+            "",
+            "Here, the guard takes `5` steps and visits *`41`* distinct positions.",
+            "",
+            "## --- Part Two ---",
+            "",
+            "There are *`6`* positions.",
+        ]
+        .join("\n");
+
+        assert_eq!(
+            puzzle_title(&markdown),
+            Some("Day 6: Guard Gallivant".into())
+        );
+        assert_eq!(first_code_block(&markdown), Some("....#\n..^..\n".into()));
+        assert_eq!(example_answer(&markdown), Some("41".into()));
+
+        assert_eq!(puzzle_title("no heading"), None);
+        assert_eq!(first_code_block("no `code` block"), None);
+        assert_eq!(example_answer("```\nx\n```\n\nNo answer."), None);
+    }
+
+    #[test]
+    fn converts_pages_without_articles() {
+        assert_eq!(
+            puzzle_to_markdown("<p>That's the right answer!</p>\n<p>Done.</p>"),
+            "That's the right answer!\n\nDone."
+        );
+    }
+
+    #[test]
+    fn joins_multiple_articles() {
+        let html = "<article><h2>One</h2></article><p>x</p><article><h2>Two</h2></article>";
+        assert_eq!(puzzle_to_markdown(html), "## One\n\n## Two");
+    }
+
+    #[test]
+    fn decodes_entities() {
+        assert_eq!(
+            decode_entities("a &lt;b&gt; &amp; &#39;c&#x27; &unknown; &"),
+            "a <b> & 'c' &unknown; &"
+        );
+    }
+
+    #[test]
+    fn skips_comments() {
+        assert_eq!(html_to_markdown("<!-- <p>x</p> --><p>y</p>"), "y");
+    }
+}
@@ -1,68 +1,360 @@
-use std::{env, fs};
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
 
-pub mod aoc_cli;
+pub mod answer;
+pub mod answers;
+pub mod aoc_client;
 pub mod commands;
+pub mod examples;
+pub mod inputs;
+pub mod limits;
+pub mod report;
 pub mod runner;
+pub mod selector;
+pub mod stats;
+pub mod submissions;
 
+pub use answer::Answer;
+pub use compare::DEFAULT_THRESHOLD;
 pub use day::*;
+pub use registry::*;
+pub use run_multi::{run_day, MultiOptions, RUN_DAY_COMMAND};
+pub use year::*;
 
+mod compare;
 mod day;
+mod history;
+mod markdown;
+mod ocr;
 mod readme_benchmarks;
+mod registry;
 mod run_multi;
+mod summary;
 mod timings;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
+pub const ANSI_RED: &str = "\x1b[31m";
+pub const ANSI_GREEN: &str = "\x1b[32m";
+
+/// Helper function that returns the data directory of a year, e.g. `data/2024`.
+/// Before several years were supported, the data of the one year lived in `data` itself. That layout is still used
+/// for the year of `AOC_YEAR` until `data/<year>` exists. To migrate, move its contents to `data/<year>`.
+#[must_use]
+pub fn data_dir(year: Year) -> PathBuf {
+    resolve_data_dir(Path::new("data"), year, Year::from_env())
+}
+
+fn resolve_data_dir(root: &Path, year: Year, default_year: Option<Year>) -> PathBuf {
+    let dir = root.join(year.to_string());
+    let is_legacy = ["inputs", "examples", "puzzles", "answers", "timings.json"]
+        .iter()
+        .any(|entry| root.join(entry).exists());
+
+    if !dir.exists() && default_year == Some(year) && is_legacy {
+        root.to_path_buf()
+    } else {
+        dir
+    }
+}
 
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn read_file(folder: &str, year: Year, day: Day) -> String {
+    try_read_file(folder, year, day).expect("could not open input file")
+}
+
+/// Helper function that reads a text file to a string, returning an error if it can not be read.
+/// Falls back to the first file in `NN/` if there is no `NN.txt`, see [`inputs`].
+/// The metadata header of examples is stripped, see [`examples`].
+pub fn try_read_file(folder: &str, year: Year, day: Day) -> io::Result<String> {
+    let cwd = env::current_dir()?;
+    let dir = cwd.join(data_dir(year)).join(folder);
+
+    let contents = match fs::read_to_string(dir.join(format!("{day}.txt"))) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            match inputs::list_txt_files(&dir.join(day.to_string()))?.first() {
+                Some(path) => fs::read_to_string(path),
+                None => Err(e),
+            }
+        }
+        result => result,
+    }?;
+
+    Ok(strip_example_header(folder, contents))
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, year: Year, day: Day, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
-        .join("data")
+        .join(data_dir(year))
         .join(folder)
         .join(format!("{day}-{part}.txt"));
     let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    strip_example_header(folder, f.expect("could not open input file"))
+}
+
+fn strip_example_header(folder: &str, contents: String) -> String {
+    if folder != "examples" {
+        return contents;
+    }
+
+    examples::split_header(&contents).1.to_string()
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constants `YEAR` and `DAY` and sets up the input and runner for each part.
+/// Parts return an `Option` of their answer, or a `Result` with a [`ParseError`](crate::ParseError)
+/// if their input can be malformed, see [`PartOutput`](answer::PartOutput).
+///
+/// Options can follow the day, separated by commas:
+///  - `1` or `2` only runs a single part of the solution.
+///  - `parse` runs a function `parse(input: &str) -> Result<T, ParseError>` once, and passes `&T` to both parts
+///    instead of the input. Its time is reported separately.
+///  - `state` lets part one share work with part two. `part_one` returns its answer along with a state,
+///    which `part_two` receives as an extra argument, e.g. `part_two(input: &str, state: &S)`.
+///    Both parts are timed without each other, and part one also runs when only part two is requested.
+///    It needs both parts, so it can't be combined with `1` or `2`.
+///  - `params { ... }` declares typed parameters, for puzzles whose examples use different constants than the real input:
+///
+/// ```ignore
+/// advent_of_code::solution!(2024, 14, params { width: i32 = 101, height: i32 = 103 });
+///
+/// pub fn part_one(input: &str, params: &Params) -> Option<usize> { ... }
+/// ```
 ///
-/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// This creates a `Params` struct that both parts receive. Its fields default to the values for real inputs,
+/// and can be overridden by the header of an example file, e.g. `width: 11`.
+///
+/// Also creates the constant `SOLUTION`, which registers the parts with the `multi` binary.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    ($year:expr, $day:expr $(, $($options:tt)*)?) => {
+        $crate::solution!(
+            @options [$year, $day] [without_params] [without_parse] [without_state] [[part_one, 1] [part_two, 2]]
+            $($($options)*)?
+        );
+    };
+
+    (@options $head:tt $params:tt $parse:tt $state:tt $parts:tt 1 $(, $($rest:tt)*)?) => {
+        $crate::solution!(@options $head $params $parse $state [[part_one, 1]] $($($rest)*)?);
+    };
+    (@options $head:tt $params:tt $parse:tt $state:tt $parts:tt 2 $(, $($rest:tt)*)?) => {
+        $crate::solution!(@options $head $params $parse $state [[part_two, 2]] $($($rest)*)?);
+    };
+    (@options $head:tt $params:tt $parse:tt $state:tt $parts:tt parse $(, $($rest:tt)*)?) => {
+        $crate::solution!(@options $head $params [with_parse] $state $parts $($($rest)*)?);
+    };
+    (@options $head:tt $params:tt $parse:tt $state:tt $parts:tt state $(, $($rest:tt)*)?) => {
+        $crate::solution!(@options $head $params $parse [with_state] $parts $($($rest)*)?);
+    };
+    (@options $head:tt $params:tt $parse:tt $state:tt $parts:tt params { $($fields:tt)* } $(, $($rest:tt)*)?) => {
+        $crate::solution!(@params_struct $($fields)*);
+        $crate::solution!(@options $head [with_params] $parse $state $parts $($($rest)*)?);
+    };
+    (@options [$year:expr, $day:expr] [$params:ident] [$parse:ident] [$state:ident] [$($parts:tt)*]) => {
+        $crate::solution!(@impl $year, $day, $params, $parse, $state, $($parts)*);
+    };
+
+    (@params_struct $( $name:ident : $ty:ty = $default:expr ),* $(,)?) => {
+        /// The parameters of this day. They default to the values for real inputs.
+        #[derive(Clone, Debug)]
+        pub struct Params {
+            $( pub $name: $ty, )*
+        }
+
+        impl Default for Params {
+            fn default() -> Self {
+                Self { $( $name: $default, )* }
+            }
+        }
+
+        impl Params {
+            /// Override the defaults with the parameters of an example. Unknown parameters are ignored.
+            pub fn from_raw(raw: &$crate::template::RawParams) -> Result<Self, $crate::ParseError> {
+                let mut params = Self::default();
+                $(
+                    if let Some(value) = raw.get(stringify!($name)) {
+                        params.$name = value.parse().map_err(|e| {
+                            $crate::ParseError::new(format!(
+                                "invalid value `{value}` for parameter `{}`: {e}",
+                                stringify!($name)
+                            ))
+                        })?;
+                    }
+                )*
+                Ok(params)
+            }
+        }
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+
+    (@params with_params, $raw:expr) => {
+        Params::from_raw($raw)
+    };
+    (@params without_params, $raw:expr) => {
+        Ok::<(), $crate::ParseError>(())
+    };
+
+    (@parse with_parse, $input:expr, $parsed:ident, $results:ident, $options:expr) => {
+        let (parse_result, $parsed) = $crate::template::runner::solve_parse(parse, $input, DAY, $options);
+        $results.push(parse_result);
+        let $parsed = $parsed.as_ref();
+    };
+    (@parse without_parse, $input:expr, $parsed:ident, $results:ident, $options:expr) => {
+        let $parsed = Some($input);
+    };
+
+    (@call with_params, $func:expr, $input:expr, $params:expr $(, $extra:expr)*) => {
+        $func($input, $params $(, $extra)*)
+    };
+    (@call without_params, $func:expr, $input:expr, $params:expr $(, $extra:expr)*) => {{
+        let _ = $params;
+        $func($input $(, $extra)*)
+    }};
+
+    (@parts without_state, $with_params:ident, $parsed:ident, $params:ident, $results:ident, $options:ident,
+        $( [$func:expr, $part:expr] )*) => {
+        $(
+            if $options.runs_part($part) {
+                $results.push(match $parsed {
+                    Some(parsed) => $crate::template::runner::solve_part(
+                        |input| $crate::solution!(@call $with_params, $func, input, &$params),
+                        parsed, YEAR, DAY, $part, $options,
+                    ),
+                    None => $crate::template::runner::PartResult::not_run(DAY, $part),
+                });
+            }
+        )*
+    };
+    (@parts with_state, $with_params:ident, $parsed:ident, $params:ident, $results:ident, $options:ident,
+        [$part_one:expr, 1] [$part_two:expr, 2]) => {
+        let state = match $parsed {
+            Some(parsed) if $options.runs_part(1) => {
+                let (result, state) = $crate::template::runner::solve_stateful_part(
+                    |input| $crate::solution!(@call $with_params, $part_one, input, &$params),
+                    parsed, YEAR, DAY, 1, $options,
+                );
+                $results.push(result);
+                state
+            }
+            Some(parsed) if $options.runs_part(2) => {
+                // part one only provides the state here, so it is neither reported nor benched.
+                let options = $crate::template::runner::RunOptions {
+                    is_quiet: true,
+                    ..Default::default()
+                };
+                $crate::template::runner::solve_stateful_part(
+                    |input| $crate::solution!(@call $with_params, $part_one, input, &$params),
+                    parsed, YEAR, DAY, 1, options,
+                ).1
+            }
+            _ => {
+                if $options.runs_part(1) {
+                    $results.push($crate::template::runner::PartResult::not_run(DAY, 1));
+                }
+                None
+            }
+        };
+
+        if $options.runs_part(2) {
+            $results.push(match ($parsed, &state) {
+                (Some(parsed), Some(state)) => $crate::template::runner::solve_part(
+                    |input| $crate::solution!(@call $with_params, $part_two, input, &$params, state),
+                    parsed, YEAR, DAY, 2, $options,
+                ),
+                _ => $crate::template::runner::PartResult::not_run(DAY, 2),
+            });
+        }
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+    (@parts with_state, $($rest:tt)*) => {
+        compile_error!("`state` passes the state of part one to part two, so it can't be combined with `1` or `2`.");
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@impl $year:expr, $day:expr, $params:ident, $parse:ident, $state:ident, $($parts:tt)*) => {
+        /// The year of the current day.
+        const YEAR: $crate::template::Year = $crate::year!($year);
+
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
+        /// The parts of this day, as run by `cargo all` and `cargo time`.
+        pub const SOLUTION: $crate::template::Solution = $crate::template::Solution {
+            year: YEAR,
+            day: DAY,
+            parts: $crate::solution!(@part_numbers $($parts)*),
+            has_parse: $crate::solution!(@has_parse $parse),
+            run: |input: &str, raw: &$crate::template::RawParams, options: $crate::template::runner::RunOptions| {
+                let params = match $crate::solution!(@params $params, raw) {
+                    Ok(params) => params,
+                    Err(e) => {
+                        let parts = $crate::solution!(@part_numbers $($parts)*);
+                        return $crate::template::runner::solve_invalid_params(YEAR, DAY, parts, &e, options);
+                    }
+                };
+                let mut results = vec![];
+                $crate::solution!(@parse $parse, input, parsed, results, options);
+                $crate::solution!(@parts $state, $params, parsed, params, results, options, $($parts)*);
+                results
+            },
+        };
+
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            if std::env::args().any(|x| x == "--all-inputs") {
+                return run_all_inputs(&SOLUTION);
+            }
+            if std::env::args().any(|x| x == "--examples") {
+                return $crate::template::examples::run_examples(&SOLUTION);
+            }
+            let input = $crate::template::read_file("inputs", YEAR, DAY);
+            let results = (SOLUTION.run)(&input, &$crate::template::RawParams::new(), RunOptions::from_args());
+            submit_results(&results, YEAR, DAY);
         }
     };
+
+    (@part_numbers $( [$func:expr, $part:expr] )*) => {
+        &[$($part),*]
+    };
+
+    (@has_parse with_parse) => {
+        true
+    };
+    (@has_parse without_parse) => {
+        false
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs, process};
+
+    use super::resolve_data_dir;
+    use crate::year;
+
+    #[test]
+    fn falls_back_to_legacy_data_dir() {
+        let root = env::temp_dir().join(format!("aoc-data-{}", process::id()));
+        fs::create_dir_all(root.join("inputs")).unwrap();
+
+        let legacy = resolve_data_dir(&root, year!(2024), Some(year!(2024)));
+        let other_year = resolve_data_dir(&root, year!(2023), Some(year!(2024)));
+        fs::create_dir_all(root.join("2024")).unwrap();
+        let migrated = resolve_data_dir(&root, year!(2024), Some(year!(2024)));
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(legacy, root);
+        assert_eq!(other_year, root.join("2023"));
+        assert_eq!(migrated, root.join("2024"));
+    }
 }
@@ -0,0 +1,109 @@
+//! Module that reads the block letters some puzzles draw as their answer, e.g. 2022 day 10.
+//! Letters are separated by at least one empty column.
+
+/// The height of a letter in pixels.
+const HEIGHT: usize = 6;
+
+/// The letters of the font, without the empty columns around them.
+static FONT: &[(char, [&str; HEIGHT])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', ["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Read the letters of a grid where `#` or `█` is a lit pixel.
+/// Returns `None` if the grid is not six rows high or contains a shape that is not a known letter.
+pub fn read_letters(grid: &str) -> Option<String> {
+    let rows: Vec<Vec<bool>> = grid
+        .lines()
+        .map(|line| line.chars().map(|c| c == '#' || c == '█').collect())
+        .collect();
+
+    let first = rows.iter().position(|row| row.contains(&true))?;
+    let last = rows.iter().rposition(|row| row.contains(&true))?;
+    let rows = &rows[first..=last];
+
+    if rows.len() != HEIGHT {
+        return None;
+    }
+
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let is_lit = |row: usize, col: usize| rows[row].get(col).copied().unwrap_or(false);
+    let is_empty_column = |col: usize| (0..HEIGHT).all(|row| !is_lit(row, col));
+
+    let mut letters = String::new();
+    let mut col = 0;
+
+    while col < width {
+        if is_empty_column(col) {
+            col += 1;
+            continue;
+        }
+
+        let start = col;
+        while col < width && !is_empty_column(col) {
+            col += 1;
+        }
+
+        let (letter, _) = FONT.iter().find(|(_, glyph)| {
+            glyph.iter().enumerate().all(|(row, pattern)| {
+                pattern.len() == col - start
+                    && pattern
+                        .chars()
+                        .enumerate()
+                        .all(|(i, c)| (c == '#') == is_lit(row, start + i))
+            })
+        })?;
+
+        letters.push(*letter);
+    }
+
+    Some(letters)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::read_letters;
+
+    #[test]
+    fn reads_letters() {
+        // the answer of 2022 day 10.
+        let grid = "\
+###..#....###...##..####.###...##..#....
+#..#.#....#..#.#..#....#.#..#.#..#.#....
+#..#.#....#..#.#..#...#..###..#....#....
+###..#....###..####..#...#..#.#....#....
+#....#....#....#..#.#....#..#.#..#.#....
+#....####.#....#..#.####.###...##..####.
+";
+        assert_eq!(read_letters(grid).as_deref(), Some("PLPAZBCL"));
+        assert_eq!(
+            read_letters(&grid.replace('#', "█")).as_deref(),
+            Some("PLPAZBCL")
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_shapes() {
+        assert_eq!(read_letters(""), None);
+        assert_eq!(read_letters("#..#\n####\n#..#\n"), None);
+        assert_eq!(read_letters("#.#\n#.#\n#.#\n#.#\n#.#\n#.#\n"), None);
+    }
+}
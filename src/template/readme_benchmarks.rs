@@ -2,10 +2,18 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
+use crate::template::stats::Stats;
 use crate::template::timings::Timings;
-use crate::template::Day;
+use crate::template::{Day, Year};
 
-static MARKER: &str = "<!--- benchmarking table --->";
+/// Every year has its own table, delimited by a pair of markers.
+fn marker(year: Year) -> String {
+    format!("<!--- benchmarking table {year} --->")
+}
+
+/// The marker of the single table that readmes had before several years were supported.
+/// A table with this marker is replaced by the table of the first year that is stored.
+const LEGACY_MARKER: &str = "<!--- benchmarking table --->";
 
 #[allow(dead_code)]
 #[derive(Debug)]
@@ -26,12 +34,17 @@ pub struct TablePosition {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(year: Year, day: Day) -> String {
+    format!("./src/bin/{year}-{day}.rs")
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+fn locate_table(readme: &str, year: Year) -> Result<TablePosition, Error> {
+    let marker = marker(year);
+    let mut matches: Vec<_> = readme.match_indices(&marker).collect();
+
+    if matches.is_empty() {
+        matches = readme.match_indices(LEGACY_MARKER).collect();
+    }
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -52,76 +65,130 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+fn construct_table(prefix: &str, year: Year, timings: Timings, total_millis: f64) -> String {
+    let header = format!("{prefix} Benchmarks ({year})");
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    // the parse column is only shown if a solution has a parse step.
+    let has_parse = timings.data.iter().any(|timing| timing.parse.is_some());
+
+    let mut lines: Vec<String> = vec![marker(year), header, String::new()];
+
+    if has_parse {
+        lines.push("| Day | Parse | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---: | :---:  |".into());
+    } else {
+        lines.push("| Day | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---:  |".into());
+    }
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
+        let path = get_path_for_bin(year, timing.day);
+        let parse = if has_parse {
+            format!(" {} |", format_stats(timing.parse.as_ref()))
+        } else {
+            String::new()
+        };
+
+        // parts that were killed for exceeding a limit show the limit instead of a time.
+        let format_part = |part: u8, stats: Option<&Stats>| match timing.exceeded {
+            Some((exceeded, limit)) if exceeded == part => format!("`{limit}`"),
+            _ => format_stats(stats),
+        };
+
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+            "| [Day {}]({}) |{parse} {} | {} |",
             timing.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            format_part(1, timing.part_1.as_ref()),
+            format_part(2, timing.part_2.as_ref())
         ));
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(marker(year));
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+fn format_stats(stats: Option<&Stats>) -> String {
+    match stats {
+        None => "`-`".into(),
+        Some(stats) if stats.samples == 1 => format!("`{stats}`"),
+        Some(stats) => format!(
+            "`{stats}` (min `{:.1?}`, p95 `{:.1?}`)",
+            stats.min, stats.p95
+        ),
+    }
+}
+
+fn update_content(
+    s: &mut String,
+    year: Year,
+    timings: Timings,
+    total_millis: f64,
+) -> Result<(), Error> {
+    let positions = locate_table(s, year)?;
+    let table = construct_table("##", year, timings, total_millis);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
+pub fn update(year: Year, timings: Timings) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, year, timings, total_millis)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, MARKER};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use super::{marker, update_content, LEGACY_MARKER};
+    use std::time::Duration;
+
+    use crate::{
+        day,
+        template::limits::Limit,
+        template::stats::Stats,
+        template::timings::{Timing, Timings},
+        template::Year,
+        year,
+    };
+
+    const YEAR: Year = year!(2024);
+
+    fn ms(millis: u64) -> Stats {
+        Stats::single(Duration::from_millis(millis))
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    parse: None,
+                    part_1: Some(ms(10)),
+                    part_2: Some(ms(20)),
                     total_nanos: 3e+10,
+                    exceeded: None,
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    parse: None,
+                    part_1: Some(ms(30)),
+                    part_2: Some(ms(40)),
                     total_nanos: 7e+10,
+                    exceeded: None,
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: Some("50ms".into()),
+                    parse: None,
+                    part_1: Some(ms(40)),
+                    part_2: Some(ms(50)),
                     total_nanos: 9e+10,
+                    exceeded: None,
                 },
             ],
         }
@@ -131,53 +198,109 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, YEAR, get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
-        let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        let mut s = format!("{} {} {}", marker(YEAR), marker(YEAR), marker(YEAR));
+        update_content(&mut s, YEAR, get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
-        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.contains("## Benchmarks"), true);
+        let mut s = format!("foo\nbar\n{}{}\nbaz", marker(YEAR), marker(YEAR));
+        update_content(&mut s, YEAR, get_mock_timings(), 190.0).unwrap();
+        assert_eq!(s.contains("## Benchmarks (2024)"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
-        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
-        assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
+        let mut s = format!("foo\nbar\n{}{}\nbaz", marker(YEAR), marker(YEAR));
+        update_content(&mut s, YEAR, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, YEAR, get_mock_timings(), 190.0).unwrap();
+        assert_eq!(s.matches(&marker(YEAR)).collect::<Vec<&str>>().len(), 2);
+        assert_eq!(
+            s.matches("## Benchmarks (2024)")
+                .collect::<Vec<&str>>()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn replaces_legacy_table() {
+        let mut s = format!("foo\n{LEGACY_MARKER}\nold\n{LEGACY_MARKER}\nbaz");
+        update_content(&mut s, YEAR, get_mock_timings(), 190.0).unwrap();
+        assert!(!s.contains(LEGACY_MARKER) && !s.contains("old"));
+        assert_eq!(s.matches(&marker(YEAR)).count(), 2);
+        assert!(s.starts_with("foo\n") && s.ends_with("\nbaz"));
+    }
+
+    #[test]
+    fn format_benched_stats() {
+        let mut s = format!("{}{}", marker(YEAR), marker(YEAR));
+        let timings = Timings {
+            data: vec![Timing {
+                day: day!(1),
+                parse: None,
+                part_1: Some(Stats::from_samples(
+                    &[5, 1, 4, 2, 3].map(Duration::from_micros),
+                )),
+                part_2: None,
+                total_nanos: 3e+3,
+                exceeded: None,
+            }],
+        };
+        update_content(&mut s, YEAR, timings, 0.003).unwrap();
+        assert!(s.contains(
+            "| [Day 1](./src/bin/2024-01.rs) | `3.0µs ± 1.6µs` (min `1.0µs`, p95 `4.8µs`) | `-` |"
+        ));
     }
 
     #[test]
     fn format_benchmarks() {
-        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", marker(YEAR), marker(YEAR));
+        update_content(&mut s, YEAR, get_mock_timings(), 190.0).unwrap();
         let expected = [
             "foo",
             "bar",
-            "<!--- benchmarking table --->",
-            "## Benchmarks",
+            "<!--- benchmarking table 2024 --->",
+            "## Benchmarks (2024)",
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/2024-01.rs) | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/2024-02.rs) | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/2024-04.rs) | `40.0ms` | `50.0ms` |",
             "",
             "**Total: 190.00ms**",
-            "<!--- benchmarking table --->",
+            "<!--- benchmarking table 2024 --->",
             "baz",
         ]
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_parse_column() {
+        let mut s = format!("{}{}", marker(YEAR), marker(YEAR));
+        let mut timings = get_mock_timings();
+        timings.data[1].parse = Some(ms(5));
+        update_content(&mut s, YEAR, timings, 195.0).unwrap();
+        assert!(s.contains("| Day | Parse | Part 1 | Part 2 |"));
+        assert!(s.contains("| [Day 1](./src/bin/2024-01.rs) | `-` | `10.0ms` | `20.0ms` |"));
+        assert!(s.contains("| [Day 2](./src/bin/2024-02.rs) | `5.0ms` | `30.0ms` | `40.0ms` |"));
+    }
+
+    #[test]
+    fn format_exceeded_limits() {
+        let mut s = format!("{}{}", marker(YEAR), marker(YEAR));
+        let mut timings = get_mock_timings();
+        timings.data[2].part_2 = None;
+        timings.data[2].exceeded = Some((2, Limit::Timeout));
+        update_content(&mut s, YEAR, timings, 150.0).unwrap();
+        assert!(s.contains("| [Day 4](./src/bin/2024-04.rs) | `40.0ms` | `TIMEOUT` |"));
+    }
 }
@@ -0,0 +1,32 @@
+/// Type-erased handles to solutions, used to run several days in one process.
+use std::collections::HashMap;
+
+use crate::template::runner::{PartResult, RunOptions};
+use crate::template::{Day, Year};
+
+/// Parameters by name, as declared in the header of an example. Real inputs have none.
+/// Each solution parses them into its own `Params`, see [`solution!`](crate::solution).
+pub type RawParams = HashMap<String, String>;
+
+/// Runs the parts of a solution against an input with the given parameters and returns their results.
+/// A solution with a parse step reports it first, see [`PARSE_PART`](crate::template::runner::PARSE_PART).
+pub type RunFn = fn(&str, &RawParams, RunOptions) -> Vec<PartResult>;
+
+/// A solution as registered by the [`solution!`](crate::solution) macro.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub year: Year,
+    pub day: Day,
+    /// The parts the solution implements.
+    pub parts: &'static [u8],
+    /// Whether the parts share a parse step, which runs before them.
+    pub has_parse: bool,
+    pub run: RunFn,
+}
+
+/// Looks up the registered solution for a day of a year, if there is one.
+pub fn find_solution(solutions: &[Solution], year: Year, day: Day) -> Option<&Solution> {
+    solutions
+        .iter()
+        .find(|solution| solution.year == year && solution.day == day)
+}
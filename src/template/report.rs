@@ -0,0 +1,292 @@
+/// Module that writes the results of a multi-day run in machine-readable formats, see [`Format`].
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+use tinyjson::JsonValue;
+
+use crate::template::answers::Check;
+use crate::template::runner::{PartResult, Status, PARSE_PART};
+use crate::template::Year;
+
+/// The format `cargo all` and `cargo time` report their results in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    /// The formatted output of every day, as the solutions print it.
+    #[default]
+    Text,
+    /// A JSON object with the results of every part.
+    Json,
+    /// One line per part, with a header.
+    Csv,
+    /// A JUnit XML report with one test suite per day and one test case per part.
+    Junit,
+}
+
+/// Write the results of a run in a machine-readable format. Returns `None` for [`Format::Text`].
+pub fn write_report(format: Format, year: Year, results: &[PartResult]) -> Option<String> {
+    match format {
+        Format::Text => None,
+        Format::Json => Some(write_json(year, results)),
+        Format::Csv => Some(write_csv(results)),
+        Format::Junit => Some(write_junit(year, results)),
+    }
+}
+
+fn write_json(year: Year, results: &[PartResult]) -> String {
+    let map = HashMap::from([
+        ("year".to_string(), JsonValue::String(year.to_string())),
+        (
+            "results".to_string(),
+            JsonValue::Array(results.iter().map(JsonValue::from).collect()),
+        ),
+    ]);
+
+    JsonValue::Object(map).stringify().unwrap()
+}
+
+fn write_csv(results: &[PartResult]) -> String {
+    let mut lines = vec!["day,part,status,answer,check,median_nanos,samples,error".to_string()];
+
+    for result in results {
+        lines.push(format!(
+            "{},{},{},{},{},{},{},{}",
+            result.day,
+            part_name(result.part),
+            result.status,
+            escape_csv(result.answer.as_deref().unwrap_or_default()),
+            result.check,
+            result.stats.median.as_nanos(),
+            result.stats.samples,
+            escape_csv(result.error.as_deref().unwrap_or_default())
+        ));
+    }
+
+    lines.join("\n")
+}
+
+/// Quote a CSV field if it contains separators, quotes or line breaks, e.g. answers like `co,de,ka`
+/// or errors like `line 1, column 5: …`.
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Parts with a wrong answer are failures, parts that did not finish are errors and unsolved parts are skipped.
+fn write_junit(year: Year, results: &[PartResult]) -> String {
+    let mut days: Vec<_> = results.iter().map(|result| result.day).collect();
+    days.dedup();
+
+    let count = |results: &[&PartResult], is_match: fn(&PartResult) -> bool| {
+        results.iter().filter(|result| is_match(result)).count()
+    };
+    let seconds = |results: &[&PartResult]| {
+        results
+            .iter()
+            .map(|result| result.stats.median.as_secs_f64())
+            .sum::<f64>()
+    };
+
+    let all: Vec<&PartResult> = results.iter().collect();
+    let mut lines = vec![
+        r#"<?xml version="1.0" encoding="UTF-8"?>"#.to_string(),
+        format!(
+            r#"<testsuites name="Advent of Code {year}" tests="{}" failures="{}" errors="{}" time="{:.6}">"#,
+            all.len(),
+            count(&all, is_failure),
+            count(&all, is_error),
+            seconds(&all)
+        ),
+    ];
+
+    for day in days {
+        let parts: Vec<&PartResult> = results.iter().filter(|result| result.day == day).collect();
+
+        lines.push(format!(
+            r#"  <testsuite name="{year} day {day}" tests="{}" failures="{}" errors="{}" skipped="{}" time="{:.6}">"#,
+            parts.len(),
+            count(&parts, is_failure),
+            count(&parts, is_error),
+            count(&parts, |result| result.status == Status::Unsolved),
+            seconds(&parts)
+        ));
+
+        for result in parts {
+            lines.push(format!(
+                r#"    <testcase name="{}" classname="{year}.day{day}" time="{:.6}">"#,
+                part_name(result.part),
+                result.stats.median.as_secs_f64()
+            ));
+
+            let answer = result.answer.as_deref().unwrap_or_default();
+            let error = result.error.as_deref().unwrap_or_default();
+
+            match result.status {
+                Status::Unsolved => lines.push(r#"      <skipped message="unsolved"/>"#.into()),
+                status if is_error(result) => lines.push(format!(
+                    r#"      <error message="{status}">{}</error>"#,
+                    escape_xml(error)
+                )),
+                _ if is_failure(result) => lines.push(format!(
+                    r#"      <failure message="wrong answer">{}</failure>"#,
+                    escape_xml(answer)
+                )),
+                _ => {}
+            }
+
+            if !answer.is_empty() {
+                lines.push(format!(
+                    "      <system-out>{}</system-out>",
+                    escape_xml(answer)
+                ));
+            }
+
+            lines.push("    </testcase>".into());
+        }
+
+        lines.push("  </testsuite>".into());
+    }
+
+    lines.push("</testsuites>".into());
+    lines.join("\n")
+}
+
+fn is_failure(result: &PartResult) -> bool {
+    result.status == Status::Solved && result.check == Check::Wrong
+}
+
+fn is_error(result: &PartResult) -> bool {
+    matches!(
+        result.status,
+        Status::Panicked | Status::Invalid | Status::Exceeded(_)
+    )
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn part_name(part: u8) -> String {
+    match part {
+        PARSE_PART => "parse".into(),
+        part => format!("part {part}"),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Format::Text => "text",
+            Format::Json => "json",
+            Format::Csv => "csv",
+            Format::Junit => "junit",
+        })
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            "junit" => Ok(Format::Junit),
+            _ => Err(format!(
+                "unknown format `{s}`, expected text, json, csv or junit."
+            )),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::{write_report, Format};
+    use crate::{
+        day,
+        template::{
+            answers::Check,
+            limits::Limit,
+            runner::{parse_results, PartResult, Status},
+            stats::Stats,
+        },
+        year,
+    };
+
+    fn results() -> Vec<PartResult> {
+        let result = |part: u8, answer: Option<&str>, status: Status, check: Check| PartResult {
+            day: day!(23),
+            part,
+            answer: answer.map(Into::into),
+            stats: Stats::single(Duration::from_micros(1500)),
+            status,
+            check,
+            error: None,
+        };
+
+        vec![
+            result(1, Some("7"), Status::Solved, Check::Correct),
+            result(2, Some("co,de,ka"), Status::Solved, Check::Wrong),
+            PartResult::exceeded(
+                day!(24),
+                1,
+                Stats::single(Duration::from_secs(5)),
+                Check::Unknown,
+                Limit::Timeout,
+                "exceeded 5s".into(),
+            ),
+        ]
+    }
+
+    #[test]
+    fn writes_json() {
+        let json = write_report(Format::Json, year!(2024), &results()).unwrap();
+        let json: JsonValue = json.parse().unwrap();
+        let lines: Vec<String> = json["results"]
+            .get::<Vec<JsonValue>>()
+            .unwrap()
+            .iter()
+            .map(|result| result.stringify().unwrap())
+            .collect();
+
+        assert_eq!(json["year"], JsonValue::String("2024".into()));
+        assert_eq!(parse_results(&lines), results());
+    }
+
+    #[test]
+    fn writes_csv() {
+        let csv = write_report(Format::Csv, year!(2024), &results()).unwrap();
+        assert_eq!(
+            csv.lines().collect::<Vec<_>>(),
+            [
+                "day,part,status,answer,check,median_nanos,samples,error",
+                "23,part 1,solved,7,correct,1500000,1,",
+                "23,part 2,solved,\"co,de,ka\",wrong,1500000,1,",
+                "24,part 1,timeout,,unknown,5000000000,1,exceeded 5s",
+            ]
+        );
+    }
+
+    #[test]
+    fn writes_junit() {
+        let xml = write_report(Format::Junit, year!(2024), &results()).unwrap();
+        assert!(xml.contains(r#"tests="3" failures="1" errors="1""#));
+        assert!(xml.contains(r#"<testsuite name="2024 day 23" tests="2" failures="1" errors="0" skipped="0" time="0.003000">"#));
+        assert!(xml.contains(r#"<failure message="wrong answer">co,de,ka</failure>"#));
+        assert!(xml.contains(r#"<error message="timeout">exceeded 5s</error>"#));
+        assert_eq!(write_report(Format::Text, year!(2024), &results()), None);
+    }
+}
@@ -1,257 +1,414 @@
-use std::{collections::HashSet, io};
+use std::collections::HashSet;
+use std::io::{self, BufRead, BufReader};
+use std::process::{self, Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::{Duration, Instant};
+use std::{env, slice, thread};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{Day, Year, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
 
 use super::{
     all_days,
+    answers::Answers,
+    find_solution,
+    limits::{memory_usage, Limit, Limits, PartLimits},
+    report::{write_report, Format},
+    runner::{format_part_result, parse_results, PartResult, RunOptions, Status, PARSE_PART},
+    stats::Stats,
+    summary::{print_summary, Summary},
     timings::{Timing, Timings},
+    try_read_file, RawParams, Solution,
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+/// The hidden `multi` subcommand that runs a single day for [`run_multi`], see [`run_day`].
+pub const RUN_DAY_COMMAND: &str = "run-day";
+
+/// How often a running day is checked against its limits.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Controls how [`run_multi`] runs and reports days.
+#[derive(Clone, Debug, Default)]
+pub struct MultiOptions {
+    /// Bench every part. Timed days always run one at a time, since parallel days would skew the benchmarks.
+    pub is_timed: bool,
+    pub limits: Limits,
+    /// The number of days that run at once.
+    pub jobs: usize,
+    /// Formats other than text print a single report once every day is done, instead of the output of each day.
+    pub format: Format,
+}
 
-    let mut need_space = false;
+/// Run several days in-process. If limits are set, each day runs in a child process that is killed if a part
+/// exceeds them, so a day that hangs or runs out of memory doesn't stop the remaining days.
+/// Formats other than text also run each day in a child process, so stdout only holds the report.
+/// With more than one job, days run concurrently in child processes, and the output of each day, including what
+/// the solution itself prints, is printed at once, in day order.
+pub fn run_multi(
+    solutions: &[Solution],
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    options: MultiOptions,
+) -> Option<Timings> {
+    let MultiOptions {
+        is_timed,
+        limits,
+        format,
+        jobs,
+    } = options;
+    let is_text = format == Format::Text;
 
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
+    let days: Vec<Day> = all_days().filter(|day| days_to_run.contains(day)).collect();
+
+    let jobs = if is_timed { 1 } else { jobs.max(1) };
+
+    let day_results = if jobs == 1 {
+        days.iter()
+            .enumerate()
+            .map(|(i, &day)| {
+                if is_text && i > 0 {
+                    println!();
+                }
+                let mut print = |line: &str| {
+                    if is_text {
+                        println!("{line}");
+                    }
+                };
+                // other formats capture the days, so nothing the solutions print ends up in the report.
+                run_solution(
+                    solutions, year, day, is_timed, &limits, !is_text, &mut print,
+                )
+            })
+            .collect()
+    } else {
+        run_parallel(solutions, year, &days, &limits, jobs, is_text)
+    };
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+    let results: Vec<PartResult> = day_results.iter().flatten().flatten().cloned().collect();
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    if let Some(report) = write_report(format, year, &results) {
+        println!("{report}");
+    }
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
+    let timings: Vec<Timing> = days
+        .iter()
+        .zip(&day_results)
+        .filter_map(|(&day, results)| Some(Timing::from_results(day, results.as_ref()?)))
+        .collect();
+
+    let is_exceeded = |result: &&PartResult| matches!(result.status, Status::Exceeded(_));
+
+    if is_text && results.iter().any(|result| is_exceeded(&result)) {
+        println!("\n{ANSI_BOLD}Exceeded limits:{ANSI_RESET}");
+        for result in results.iter().filter(is_exceeded) {
+            if let Status::Exceeded(limit) = result.status {
+                let step = match result.part {
+                    PARSE_PART => "parse".to_string(),
+                    part => format!("part {part}"),
+                };
+                println!(
+                    "Day {} {step}: {limit} ({})",
+                    result.day,
+                    result.error.as_deref().unwrap_or_default()
+                );
             }
-        });
+        }
+    }
+
+    if is_text && !is_timed {
+        println!();
+        print_summary(&Summary::from_results(&results));
+    }
 
     if is_timed {
         let timings = Timings { data: timings };
-        let total_millis = timings.total_millis();
-        println!(
-            "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
-        );
+        if is_text {
+            let total_millis = timings.total_millis();
+            println!(
+                "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+            );
+        }
         Some(timings)
     } else {
         None
     }
 }
 
-#[allow(dead_code)]
-#[derive(Debug)]
-pub enum Error {
-    BrokenPipe,
-    IO(io::Error),
-}
+/// Run days on `jobs` threads, each in a child process whose output is captured, see [`run_multi`].
+/// If `is_printed`, a day is printed as soon as it and all days before it are done.
+/// Returns the results of each day, in the order of `days`.
+fn run_parallel(
+    solutions: &[Solution],
+    year: Year,
+    days: &[Day],
+    limits: &Limits,
+    jobs: usize,
+    is_printed: bool,
+) -> Vec<Option<Vec<PartResult>>> {
+    let next_day = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            let (sender, next_day) = (sender.clone(), &next_day);
+
+            scope.spawn(move || {
+                while let Some(&day) = days.get(next_day.fetch_add(1, Ordering::Relaxed)) {
+                    let mut output = String::new();
+                    let mut capture = |line: &str| {
+                        output.push_str(line);
+                        output.push('\n');
+                    };
+                    let results =
+                        run_solution(solutions, year, day, false, limits, true, &mut capture);
+
+                    if sender.send((day, output, results)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
 
-impl From<std::io::Error> for Error {
-    fn from(e: std::io::Error) -> Self {
-        Error::IO(e)
-    }
-}
+        // the receiver stops once every worker dropped its sender.
+        drop(sender);
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
+        let mut finished: Vec<Option<(String, Option<Vec<PartResult>>)>> =
+            days.iter().map(|_| None).collect();
+        let mut printed = 0;
 
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
-pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::Day;
-    use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
-        thread,
-    };
+        for (day, output, results) in receiver {
+            if let Some(i) = days.iter().position(|&d| d == day) {
+                finished[i] = Some((output, results));
+            }
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+            while let Some(Some((output, _))) = finished.get(printed).filter(|_| is_printed) {
+                if printed > 0 {
+                    println!();
+                }
+                print!("{output}");
+                printed += 1;
+            }
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        finished
+            .into_iter()
+            .map(|day| day.and_then(|(_, results)| results))
+            .collect()
+    })
+}
 
-        if is_release {
-            args.push("--release");
-        }
+/// Run a single day in-process and print its results as JSON lines, for [`run_multi`] to read.
+pub fn run_day(solutions: &[Solution], year: Year, day: Day, is_timed: bool) {
+    let Some(solution) = find_solution(solutions, year, day) else {
+        eprintln!("Day {day} is not solved.");
+        process::exit(1);
+    };
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+    let input = match try_read_file("inputs", year, day) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to read the input of day {day}: {e}");
+            process::exit(1);
         }
+    };
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+    let options = RunOptions {
+        is_timed,
+        is_json: true,
+        ..RunOptions::default()
+    };
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+    (solution.run)(&input, &RawParams::new(), options);
+}
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+/// Run all registered parts of a day, in a child process if limits are set or `is_captured`.
+/// Its output is passed to `emit` line by line. If `is_captured`, that includes everything the solution prints,
+/// also to stderr, so the day can be printed at once. Returns `None` if the day has not been scaffolded yet
+/// or its input is missing.
+fn run_solution(
+    solutions: &[Solution],
+    year: Year,
+    day: Day,
+    is_timed: bool,
+    limits: &Limits,
+    is_captured: bool,
+    emit: &mut dyn FnMut(&str),
+) -> Option<Vec<PartResult>> {
+    emit(&format!("{ANSI_BOLD}Day {day}{ANSI_RESET}"));
+    emit("------");
+
+    let Some(solution) = find_solution(solutions, year, day) else {
+        emit("Not solved.");
+        return None;
+    };
 
-        let mut output = vec![];
+    let Ok(input) = try_read_file("inputs", year, day) else {
+        emit("Not solved.");
+        return None;
+    };
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
+    if limits.is_unlimited() && !is_captured {
+        let options = RunOptions {
+            is_timed,
+            is_quiet: true,
+            ..RunOptions::default()
+        };
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        let results = (solution.run)(&input, &RawParams::new(), options);
+        for result in &results {
+            emit(&format_part_result(result));
         }
-
-        thread.join().unwrap();
-        cmd.wait()?;
-
-        Ok(output)
+        return Some(results);
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
-
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+    let mut results = match run_child(solution, is_timed, limits, is_captured, emit) {
+        Ok(results) => results,
+        Err(e) => {
+            emit(&format!("Failed to run day {day}: {e}"));
+            vec![]
+        }
+    };
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+    // parts without a result did not run, e.g. because the child crashed or the parse step exceeded a limit.
+    // after a part exceeded a limit, the remaining parts are left out instead.
+    if !results
+        .iter()
+        .any(|result| result.part != PARSE_PART && matches!(result.status, Status::Exceeded(_)))
+    {
+        for &part in solution.parts {
+            if !results.iter().any(|result| result.part == part) {
+                let result = PartResult::not_run(day, part);
+                emit(&format_part_result(&result));
+                results.push(result);
+            }
+        }
+    }
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+    Some(results)
+}
 
-                timings.total_nanos += nanos;
-            });
+/// Run a day with [`run_day`] in a child process and emit its results as they arrive, along with the stderr
+/// of the child if `is_captured`.
+/// Each step is checked against its own limits, see [`Limits::for_part`]. If a step exceeds them, the child is killed
+/// and the limit is reported for that step.
+fn run_child(
+    solution: &Solution,
+    is_timed: bool,
+    limits: &Limits,
+    is_captured: bool,
+    emit: &mut dyn FnMut(&str),
+) -> io::Result<Vec<PartResult>> {
+    let mut command = Command::new(env::current_exe()?);
+    command
+        .args(["--year", &solution.year.to_string()])
+        .args([RUN_DAY_COMMAND, &solution.day.to_string()])
+        .stdout(Stdio::piped());
 
-        timings
+    if is_timed {
+        command.arg("--time");
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    if is_captured {
+        command.stderr(Stdio::piped());
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
-    }
+    let mut child = command.spawn()?;
+    let stdout = child.stdout.take().expect("stdout of the child is piped");
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+    // read on separate threads, so the limits can be checked while the child is silent.
+    let (sender, receiver) = mpsc::channel();
+    let forward = |output: Box<dyn io::Read + Send>, sender: mpsc::Sender<String>| {
+        thread::spawn(move || {
+            for line in BufReader::new(output).lines().map_while(Result::ok) {
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+    };
+
+    if let Some(stderr) = child.stderr.take() {
+        forward(Box::new(stderr), sender.clone());
     }
+    forward(Box::new(stdout), sender);
+
+    let mut results: Vec<PartResult> = vec![];
+    let mut part_start = Instant::now();
+
+    // the step that is running: the parse step if it has no result yet, or else the first part without a result.
+    let running_step = |results: &[PartResult]| {
+        let has_result = |part: u8| results.iter().any(|result| result.part == part);
+        if solution.has_parse && !has_result(PARSE_PART) {
+            Some(PARSE_PART)
+        } else {
+            solution
+                .parts
+                .iter()
+                .copied()
+                .find(|&part| !has_result(part))
+        }
+    };
+    let step_limits = |step: Option<u8>| {
+        step.map_or_else(PartLimits::default, |part| {
+            limits.for_part(solution.day, part)
+        })
+    };
 
-    #[cfg(feature = "test_lib")]
-    mod tests {
-        use super::parse_exec_time;
-
-        use crate::day;
-
-        #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+    let mut step = running_step(&results);
+    let mut part_limits = step_limits(step);
+
+    let exceeded = loop {
+        match receiver.recv_timeout(POLL_INTERVAL) {
+            Ok(line) => match parse_results(slice::from_ref(&line)).pop() {
+                Some(result) => {
+                    emit(&format_part_result(&result));
+                    results.push(result);
+                    part_start = Instant::now();
+                    step = running_step(&results);
+                    part_limits = step_limits(step);
+                }
+                // output of the solution itself, e.g. debug prints or panic messages.
+                None => emit(&line),
+            },
+            Err(RecvTimeoutError::Disconnected) => break None,
+            Err(RecvTimeoutError::Timeout) => {}
         }
 
-        #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+        if part_limits
+            .timeout
+            .is_some_and(|timeout| part_start.elapsed() > timeout)
+        {
+            break Some(Limit::Timeout);
         }
 
-        #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
+        if part_limits
+            .memory
+            .is_some_and(|max| memory_usage(child.id()).is_some_and(|bytes| bytes > max))
+        {
+            break Some(Limit::Memory);
+        }
+    };
+
+    if let Some(limit) = exceeded {
+        // the child may have exited on its own in the meantime, which is fine.
+        let _ = child.kill();
+
+        if let Some(part) = step {
+            let answers = Answers::read_from_file(solution.year, solution.day);
+            let result = PartResult::exceeded(
+                solution.day,
+                part,
+                Stats::single(part_start.elapsed()),
+                answers.check(part, None),
+                limit,
+                part_limits.describe(limit),
             );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
+            emit(&format_part_result(&result));
+            results.push(result);
         }
     }
+
+    child.wait()?;
+    Ok(results)
 }
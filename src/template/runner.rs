@@ -1,35 +1,507 @@
 /// Encapsulates code that interacts with solution functions.
+use std::collections::HashMap;
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{stdout, Write};
-use std::process::Output;
+use std::panic::{self, AssertUnwindSafe};
+use std::str::FromStr;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{env, process};
 
-use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use tinyjson::JsonValue;
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+use crate::template::answer::PartOutput;
+use crate::template::answers::{store_answer, Answers, Check};
+use crate::template::inputs::read_inputs;
+use crate::template::limits::Limit;
+use crate::template::stats::Stats;
+use crate::template::submissions::{Submissions, Verdict};
+use crate::template::{
+    aoc_client, Answer, Day, RawParams, Solution, Year, ANSI_ITALIC, ANSI_RESET,
+};
+use crate::template::{ANSI_BOLD, ANSI_GREEN, ANSI_RED};
+use crate::ParseError;
+
+const WARM_UP_TIME: Duration = Duration::from_millis(100);
+const MEASUREMENT_TIME: Duration = Duration::from_secs(1);
+const MIN_SAMPLES: usize = 10;
+const MAX_SAMPLES: usize = 10_000;
+
+/// Controls how a solution part is run and reported.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RunOptions {
+    /// Bench the part instead of running it once.
+    pub is_timed: bool,
+    /// Print one JSON line per part instead of the formatted result.
+    pub is_json: bool,
+    /// Don't print anything, the caller reports the result.
+    pub is_quiet: bool,
+    /// Only run this part instead of every part, e.g. to check an example of a single part.
+    pub part: Option<u8>,
+}
+
+impl RunOptions {
+    /// Reads the options from the flags passed to a solution binary.
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        Self {
+            is_timed: args.iter().any(|x| x == "--time"),
+            is_json: args.iter().any(|x| x == "--json"),
+            is_quiet: false,
+            part: args
+                .iter()
+                .position(|x| x == "--part")
+                .and_then(|i| args.get(i + 1)?.parse().ok()),
+        }
+    }
+
+    /// Whether a part is run with these options.
+    pub fn runs_part(self, part: u8) -> bool {
+        self.part.is_none() || self.part == Some(part)
+    }
+
+    /// Whether results are printed in the human-readable format.
+    fn is_formatted(self) -> bool {
+        !self.is_json && !self.is_quiet
+    }
+}
+
+/// The state a solution part finished in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    Unsolved,
+    Panicked,
+    /// The input could not be parsed, see [`ParseError`].
+    Invalid,
+    /// The part was killed for exceeding a limit of a multi-day run, see [`Limits`](crate::template::limits::Limits).
+    Exceeded(Limit),
+}
+
+/// The part number under which the parse step of a solution is reported, see [`solve_parse`].
+pub const PARSE_PART: u8 = 0;
+
+/// The outcome of running a single solution part.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartResult {
+    pub day: Day,
+    pub part: u8,
+    pub answer: Option<String>,
+    /// Execution time statistics. Only a single sample is taken unless the part is benched.
+    pub stats: Stats,
+    pub status: Status,
+    /// The answer checked against the stored answer of the part.
+    pub check: Check,
+    /// Why the part has no answer, e.g. where its input is malformed.
+    pub error: Option<String>,
+}
+
+impl PartResult {
+    /// The result of a part that could not run because a step it depends on panicked,
+    /// i.e. parsing its input or the part that computes its state.
+    pub fn not_run(day: Day, part: u8) -> Self {
+        Self {
+            day,
+            part,
+            answer: None,
+            stats: Stats::single(Duration::ZERO),
+            status: Status::Panicked,
+            check: Check::Unknown,
+            error: None,
+        }
+    }
+
+    /// The result of a part whose input is malformed.
+    fn invalid(day: Day, part: u8, stats: Stats, check: Check, error: &ParseError) -> Self {
+        Self {
+            day,
+            part,
+            answer: None,
+            stats,
+            status: Status::Invalid,
+            check,
+            error: Some(error.to_string()),
+        }
+    }
+
+    /// The result of a part that was killed for exceeding a limit, e.g. `exceeded 300s`.
+    pub fn exceeded(
+        day: Day,
+        part: u8,
+        stats: Stats,
+        check: Check,
+        limit: Limit,
+        description: String,
+    ) -> Self {
+        Self {
+            day,
+            part,
+            answer: None,
+            stats,
+            status: Status::Exceeded(limit),
+            check,
+            error: Some(description),
+        }
+    }
+}
+
+/// Submit the answer of the part that was passed to `--submit`, if any.
+pub fn submit_results(results: &[PartResult], year: Year, day: Day) {
+    for result in results {
+        if let (Some(answer), true) = (&result.answer, result.part != PARSE_PART) {
+            submit_result(answer, year, day, result.part);
+        }
+    }
+}
+
+/// Run the parse step of a solution, timed and reported like a part.
+/// Returns the parsed input, or `None` if the input is malformed or parsing panicked.
+pub fn solve_parse<I: Clone, T>(
+    func: impl Fn(I) -> Result<T, ParseError>,
+    input: I,
+    day: Day,
+    options: RunOptions,
+) -> (PartResult, Option<T>) {
+    let run = panic::catch_unwind(AssertUnwindSafe(|| {
+        run_timed(&func, input, options, |_| {
+            if options.is_formatted() {
+                print!("Parse:");
+            }
+        })
+    }));
+
+    let (parsed, result) = match run {
+        Ok((Ok(parsed), stats)) => {
+            if options.is_formatted() {
+                print!("\r");
+                println!("Parse:{}", format_duration(&stats));
+            }
+
+            let result = PartResult {
+                day,
+                part: PARSE_PART,
+                answer: None,
+                stats,
+                status: Status::Solved,
+                check: Check::Unknown,
+                error: None,
+            };
+
+            (Some(parsed), result)
+        }
+        Ok((Err(e), stats)) => {
+            if options.is_formatted() {
+                print!("\r");
+                println!("Parse: ✖ invalid input: {e}");
+            }
+
+            (
+                None,
+                PartResult::invalid(day, PARSE_PART, stats, Check::Unknown, &e),
+            )
+        }
+        Err(_) => {
+            if options.is_formatted() {
+                print!("\r");
+                println!("Parse: ✖ (panicked)");
+            }
+
+            (None, PartResult::not_run(day, PARSE_PART))
+        }
+    };
+
+    if options.is_json && !options.is_quiet {
+        println!("{}", JsonValue::from(&result).stringify().unwrap());
+    }
+
+    (result, parsed)
+}
+
+/// Run a solution part, report its result and return it in a structured form.
+/// A panicking part is reported with [`Status::Panicked`] instead of unwinding into the caller.
+pub fn solve_part<I: Clone, T: PartOutput>(
+    func: impl Fn(I) -> T,
+    input: I,
+    year: Year,
+    day: Day,
+    part: u8,
+    options: RunOptions,
+) -> PartResult {
+    solve_stateful_part(|input| (func(input), ()), input, year, day, part, options).0
+}
+
+/// Run a solution part that also returns a state for the next part, see [`solve_part`].
+/// Only the part itself is timed. Returns the state of the first run, or `None` if the part panicked.
+pub fn solve_stateful_part<I: Clone, T: PartOutput, S>(
+    func: impl Fn(I) -> (T, S),
+    input: I,
+    year: Year,
+    day: Day,
+    part: u8,
+    options: RunOptions,
+) -> (PartResult, Option<S>) {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let func = |input: I| {
+        let (result, state) = func(input);
+        (result.into_answer(), state)
+    };
+
+    let run = panic::catch_unwind(AssertUnwindSafe(|| {
+        run_timed(func, input, options, |(result, _)| {
+            if options.is_formatted() {
+                print_result(result.as_ref().unwrap_or(&None), &part_str, "");
+            }
+        })
+    }));
+
+    let answers = Answers::read_from_file(year, day);
+
+    let (result, state) = match run {
+        Ok(((Err(e), state), stats)) => {
+            if options.is_formatted() {
+                print!("\r");
+                println!("{part_str}: ✖ invalid input: {e}");
+            }
+
+            let check = answers.check(part, None);
+            (
+                PartResult::invalid(day, part, stats, check, &e),
+                Some(state),
+            )
+        }
+        Ok(((Ok(result), state), stats)) => {
+            let answer = result.as_ref().map(ToString::to_string);
+            let check = answers.check(part, answer.as_deref());
+
+            if options.is_formatted() {
+                let details = format!("{}{}", format_check(check), format_duration(&stats));
+                print_result(&result, &part_str, &details);
+            }
+
+            let result = PartResult {
+                day,
+                part,
+                status: if answer.is_some() {
+                    Status::Solved
+                } else {
+                    Status::Unsolved
+                },
+                answer,
+                stats,
+                check,
+                error: None,
+            };
+
+            (result, Some(state))
+        }
+        Err(_) => {
+            if options.is_formatted() {
+                print!("\r");
+                println!("{part_str}: ✖ (panicked)");
+            }
+
+            let result = PartResult {
+                day,
+                part,
+                answer: None,
+                stats: Stats::single(Duration::ZERO),
+                status: Status::Panicked,
+                check: answers.check(part, None),
+                error: None,
+            };
+
+            (result, None)
+        }
+    };
+
+    if options.is_json && !options.is_quiet {
+        println!("{}", JsonValue::from(&result).stringify().unwrap());
+    }
+
+    (result, state)
+}
+
+/// Report every part that runs as invalid, since the parameters of an example could not be parsed.
+/// Returns their results like a solution would, see [`solution!`](crate::solution).
+pub fn solve_invalid_params(
+    year: Year,
+    day: Day,
+    parts: &[u8],
+    error: &ParseError,
+    options: RunOptions,
+) -> Vec<PartResult> {
+    let answers = Answers::read_from_file(year, day);
+
+    parts
+        .iter()
+        .filter(|&&part| options.runs_part(part))
+        .map(|&part| {
+            let check = answers.check(part, None);
+            let result =
+                PartResult::invalid(day, part, Stats::single(Duration::ZERO), check, error);
+
+            if options.is_formatted() {
+                println!("Part {part}: ✖ invalid input: {error}");
+            }
+            if options.is_json && !options.is_quiet {
+                println!("{}", JsonValue::from(&result).stringify().unwrap());
+            }
+
+            result
+        })
+        .collect()
+}
+
+/// Run every part of a solution against every input of its day and print the answers side by side.
+/// Answers are checked against the expected answers of each input. Exits with a non-zero status if any is wrong.
+pub fn run_all_inputs(solution: &Solution) {
+    let inputs = match read_inputs(solution.year, solution.day) {
+        Ok(inputs) => inputs,
+        Err(e) => {
+            eprintln!("Failed to read inputs: {e}");
+            process::exit(1);
+        }
+    };
+
+    let options = RunOptions {
+        is_quiet: true,
+        ..RunOptions::default()
+    };
+
+    let rows: Vec<(&str, Vec<(String, Check)>)> = inputs
+        .iter()
+        .map(|input| {
+            let results = (solution.run)(&input.text, &RawParams::new(), options);
+            let cells = solution
+                .parts
+                .iter()
+                .map(|&part| match results.iter().find(|r| r.part == part) {
+                    Some(result) => (
+                        format_answer(result),
+                        input.answers.check(part, result.answer.as_deref()),
+                    ),
+                    None => ("✖".into(), Check::Unknown),
+                })
+                .collect();
+            (input.name.as_str(), cells)
+        })
+        .collect();
+
+    let name_width = rows
+        .iter()
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or(0)
+        .max(5);
+
+    let cell_widths: Vec<usize> = solution
+        .parts
+        .iter()
+        .enumerate()
+        .map(|(i, _)| {
+            rows.iter()
+                .map(|(_, cells)| cells[i].0.chars().count())
+                .max()
+                .unwrap_or(0)
+                .max(6)
+        })
+        .collect();
+
+    print!("{ANSI_BOLD}{:<name_width$}{ANSI_RESET}", "Input");
+    for (part, width) in solution.parts.iter().zip(&cell_widths) {
+        print!(
+            "    {ANSI_BOLD}{:<width$}{ANSI_RESET}  ",
+            format!("Part {part}")
+        );
+    }
+    println!();
+
+    for (name, cells) in &rows {
+        print!("{name:<name_width$}");
+        for ((answer, check), width) in cells.iter().zip(&cell_widths) {
+            let check = match check {
+                Check::Unknown => "  ".into(),
+                check => format_check(*check),
+            };
+            print!("    {answer:<width$}{check}");
+        }
+        println!();
+    }
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    let is_wrong = rows
+        .iter()
+        .any(|(_, cells)| cells.iter().any(|(_, check)| *check == Check::Wrong));
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    if is_wrong {
+        process::exit(1);
     }
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+fn format_answer(result: &PartResult) -> String {
+    match (&result.answer, result.status) {
+        (_, Status::Panicked) => "✖ (panicked)".into(),
+        (_, Status::Invalid) => "✖ (invalid input)".into(),
+        (_, Status::Exceeded(limit)) => format!("✖ ({limit})"),
+        (Some(answer), _) if answer.contains('\n') => "▼ (multi-line)".into(),
+        (Some(answer), _) => answer.clone(),
+        (None, _) => "✖".into(),
+    }
+}
+
+/// Format a result the way [`solve_part`] and [`solve_parse`] report it, e.g. for results parsed from the output of another process.
+/// Multi-line answers span several lines.
+pub fn format_part_result(result: &PartResult) -> String {
+    let label = match result.part {
+        PARSE_PART => "Parse".to_string(),
+        part => format!("Part {part}"),
+    };
+    let error = result.error.as_deref().unwrap_or_default();
+    let details = format!(
+        "{}{}",
+        format_check(result.check),
+        format_duration(&result.stats)
+    );
+
+    match (result.status, &result.answer) {
+        (Status::Solved, _) if result.part == PARSE_PART => {
+            format!("{label}:{}", format_duration(&result.stats))
+        }
+        (Status::Solved | Status::Unsolved, Some(answer)) if answer.contains('\n') => {
+            format!("{label}: ▼ {details}\n{answer}")
+        }
+        (Status::Solved | Status::Unsolved, Some(answer)) => {
+            format!("{label}: {ANSI_BOLD}{answer}{ANSI_RESET}{details}")
+        }
+        (Status::Solved | Status::Unsolved, None) => format!("{label}: ✖{details}"),
+        (Status::Panicked, _) => format!("{label}: ✖ (panicked)"),
+        (Status::Invalid, _) => format!("{label}: ✖ invalid input: {error}"),
+        (Status::Exceeded(limit), _) => {
+            format!("{label}: ✖ {ANSI_BOLD}{limit}{ANSI_RESET} ({error})")
+        }
+    }
+}
+
+/// Parse the JSON lines emitted by a solution binary run with `--json`.
+/// Lines that are not part results, e.g. debug output of a solution, are skipped.
+pub fn parse_results(output: &[String]) -> Vec<PartResult> {
+    output
+        .iter()
+        .filter_map(|line| JsonValue::from_str(line).ok())
+        .filter_map(|json| PartResult::try_from(&json).ok())
+        .collect()
+}
+
+/// Run a solution part. The behavior differs depending on whether the part is timed:
+///  1. by default, the function is executed once.
+///  2. with `--time`, the function is benched (see [`bench`]).
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
+    options: RunOptions,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, Stats) {
     let timer = Instant::now();
     let result = {
         let input = input.clone();
@@ -43,58 +515,72 @@ fn run_timed<I: Clone, T>(
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    let stats = if options.is_timed {
+        bench(func, input, &base_time, options)
     } else {
-        (base_time, 1)
+        Stats::single(base_time)
     };
 
-    (result, run.0, run.1)
+    (result, stats)
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
-
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
-
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
-
-    let mut timers: Vec<Duration> = vec![];
+/// Bench a solution part.
+/// After warming up for `WARM_UP_TIME`, samples are taken for approx. `MEASUREMENT_TIME`,
+/// bounded by `MIN_SAMPLES` and `MAX_SAMPLES`. The first run does not count towards the samples.
+fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    options: RunOptions,
+) -> Stats {
+    if options.is_formatted() {
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout().flush();
+    }
 
-    for _ in 0..bench_iterations {
+    let run = || {
         // need a clone here to make the borrow checker happy.
         let cloned = input.clone();
         let timer = Instant::now();
         black_box(func(black_box(cloned)));
-        timers.push(timer.elapsed());
+        timer.elapsed()
+    };
+
+    // the first run already warmed up parts that are slower than the warm-up time.
+    let warm_up = Instant::now();
+    while *base_time + warm_up.elapsed() < WARM_UP_TIME {
+        run();
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    let mut timers: Vec<Duration> = vec![];
+    let measurement = Instant::now();
+
+    while timers.len() < MIN_SAMPLES
+        || (timers.len() < MAX_SAMPLES && measurement.elapsed() < MEASUREMENT_TIME)
+    {
+        timers.push(run());
+    }
+
+    Stats::from_samples(&timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+fn format_check(check: Check) -> String {
+    match check {
+        Check::Correct => format!(" {ANSI_GREEN}✔{ANSI_RESET}"),
+        Check::Wrong => format!(" {ANSI_RED}✘{ANSI_RESET}"),
+        Check::Unknown => String::new(),
+    }
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
-    } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+fn format_duration(stats: &Stats) -> String {
+    match (stats.samples, stats.outliers) {
+        (1, _) => format!(" ({stats})"),
+        (samples, 0) => format!(" ({stats} @ {samples} samples)"),
+        (samples, outliers) => format!(" ({stats} @ {samples} samples, {outliers} outliers)"),
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn print_result(result: &Option<Answer>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
@@ -131,16 +617,15 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
 
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+///  2. a session cookie is configured.
+///  3. the submission log does not already rule out the answer.
+///
+/// The verdict is added to the submission log, correct answers are stored.
+fn submit_result(result: &str, year: Year, day: Day, part: u8) {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
-        return None;
+        return;
     }
 
     if args.len() < 3 {
@@ -156,14 +641,264 @@ fn submit_result<T: Display>(
     };
 
     if part_submit != part {
-        return None;
+        return;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+    let mut submissions = Submissions::read_from_file(year, day);
+
+    if let Err(reason) = submissions.check(part, result) {
+        eprintln!("Refusing to submit: {reason}");
         process::exit(1);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    println!("Submitting result...");
+
+    let response = match aoc_client::submit(year, day, part, result) {
+        Ok(response) => response,
+        Err(e) => {
+            eprintln!("failed to submit: {e}");
+            process::exit(1);
+        }
+    };
+
+    let verdict = Verdict::from_output(&response);
+    match submissions.record(part, result, verdict) {
+        Ok(()) => {
+            if let Err(e) = submissions.store_file(year, day) {
+                eprintln!("Failed to store submission log: {e}");
+            }
+        }
+        Err(e) => eprintln!("Failed to record submission: {e}"),
+    }
+
+    if aoc_client::is_correct_answer(&response) {
+        match store_answer(year, day, part, result) {
+            Ok(()) => println!("Stored answer for day {day}, part {part}."),
+            Err(e) => eprintln!("Failed to store answer: {e}"),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Status::Solved => "solved",
+            Status::Unsolved => "unsolved",
+            Status::Panicked => "panicked",
+            Status::Invalid => "invalid",
+            Status::Exceeded(Limit::Timeout) => "timeout",
+            Status::Exceeded(Limit::Memory) => "oom",
+        })
+    }
+}
+
+impl FromStr for Status {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "solved" => Ok(Status::Solved),
+            "unsolved" => Ok(Status::Unsolved),
+            "panicked" => Ok(Status::Panicked),
+            "invalid" => Ok(Status::Invalid),
+            "timeout" => Ok(Status::Exceeded(Limit::Timeout)),
+            "oom" => Ok(Status::Exceeded(Limit::Memory)),
+            _ => Err(format!("unknown status `{s}`.")),
+        }
+    }
+}
+
+impl From<&PartResult> for JsonValue {
+    fn from(value: &PartResult) -> Self {
+        let mut map = HashMap::from(&value.stats);
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "answer".into(),
+            match &value.answer {
+                Some(answer) => JsonValue::String(answer.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert("status".into(), JsonValue::String(value.status.to_string()));
+        map.insert("check".into(), JsonValue::String(value.check.to_string()));
+
+        if let Some(error) = &value.error {
+            map.insert("error".into(), JsonValue::String(error.clone()));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartResult {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected part result to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected result.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .filter(|&&part| part == f64::from(PARSE_PART) || part == 1.0 || part == 2.0)
+            .ok_or("Expected result.part to be 0 (parse), 1 or 2.")?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected result.answer to be null or string.")?;
+
+        let stats = Stats::try_from(json)?;
+
+        let status = json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected result.status to be a string.")?
+            .parse()?;
+
+        let check = json
+            .get("check")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected result.check to be a string.")?
+            .parse()?;
+
+        let error = match json.get("error") {
+            Some(error) => Some(
+                error
+                    .get::<String>()
+                    .ok_or("Expected result.error to be a string.")?
+                    .clone(),
+            ),
+            None => None,
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(PartResult {
+            day,
+            part: *part as u8,
+            answer: answer.cloned(),
+            stats,
+            status,
+            check,
+            error,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::{parse_results, solve_invalid_params, PartResult, RunOptions, Status};
+    use crate::{
+        day,
+        template::{answers::Check, limits::Limit, stats::Stats},
+        year, ParseError,
+    };
+
+    #[test]
+    fn round_trips_results() {
+        let result = PartResult {
+            day: day!(1),
+            part: 2,
+            answer: Some("12".into()),
+            stats: Stats::from_samples(&[Duration::from_nanos(74130), Duration::from_nanos(74200)]),
+            status: Status::Solved,
+            check: Check::Correct,
+            error: None,
+        };
+
+        let line = JsonValue::from(&result).stringify().unwrap();
+        assert_eq!(parse_results(&[line]), vec![result]);
+    }
+
+    #[test]
+    fn round_trips_errors() {
+        let result = PartResult {
+            day: day!(5),
+            part: 1,
+            answer: None,
+            stats: Stats::single(Duration::from_nanos(300)),
+            status: Status::Invalid,
+            check: Check::Unknown,
+            error: Some("line 2, column 4: expected `|`.".into()),
+        };
+
+        let line = JsonValue::from(&result).stringify().unwrap();
+        assert_eq!(parse_results(&[line]), vec![result]);
+
+        let result = PartResult::exceeded(
+            day!(6),
+            2,
+            Stats::single(Duration::from_secs(5)),
+            Check::Unknown,
+            Limit::Timeout,
+            "exceeded 5s".into(),
+        );
+
+        let line = JsonValue::from(&result).stringify().unwrap();
+        assert_eq!(parse_results(&[line]), vec![result]);
+    }
+
+    #[test]
+    fn parses_with_patterns_in_output() {
+        let res = parse_results(&[
+            "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
+            r#"{"day":"01","part":1,"answer":"@ ( ) ms","median_nanos":2000000000,"min_nanos":1900000000,"p95_nanos":2100000000,"stddev_nanos":50000000,"samples":5,"outliers":0,"status":"solved","check":"unknown"}"#.into(),
+            "{ not json".into(),
+            r#"{"day":"01","part":2,"answer":null,"median_nanos":100,"min_nanos":100,"p95_nanos":100,"stddev_nanos":0,"samples":1,"outliers":0,"status":"unsolved","check":"wrong"}"#.into(),
+            "".into(),
+        ]);
+
+        assert_eq!(res.len(), 2);
+        assert_eq!(res[0].answer.as_deref(), Some("@ ( ) ms"));
+        assert_eq!(res[0].stats.median, Duration::from_secs(2));
+        assert_eq!(res[0].stats.samples, 5);
+        assert_eq!(res[1].answer, None);
+        assert_eq!(res[1].status, Status::Unsolved);
+        assert_eq!(res[1].check, Check::Wrong);
+    }
+
+    #[test]
+    fn skips_malformed_results() {
+        let res = parse_results(&[
+            r#"{"day":"01","part":1,"answer":null,"median_nanos":0,"samples":1,"status":"solved","check":"unknown"}"#.into(),
+            r#"{"day":"26","part":1,"answer":null,"median_nanos":0,"min_nanos":0,"p95_nanos":0,"stddev_nanos":0,"samples":1,"outliers":0,"status":"solved","check":"unknown"}"#.into(),
+            r#"{"day":"01","part":3,"answer":null,"median_nanos":0,"min_nanos":0,"p95_nanos":0,"stddev_nanos":0,"samples":1,"outliers":0,"status":"solved","check":"unknown"}"#.into(),
+            r#"{"day":"01","part":1,"answer":null,"median_nanos":0,"min_nanos":0,"p95_nanos":0,"stddev_nanos":0,"samples":1,"outliers":0,"status":"done","check":"unknown"}"#.into(),
+        ]);
+
+        assert!(res.is_empty());
+    }
+
+    #[test]
+    fn reports_invalid_params() {
+        let error = ParseError::new("invalid value `abc` for parameter `width`");
+        let options = RunOptions {
+            is_quiet: true,
+            part: Some(2),
+            ..RunOptions::default()
+        };
+
+        let res = solve_invalid_params(year!(2015), day!(1), &[1, 2], &error, options);
+
+        assert_eq!(res.len(), 1);
+        assert_eq!((res[0].part, res[0].status), (2, Status::Invalid));
+        assert_eq!(res[0].error, Some(error.to_string()));
+    }
 }
@@ -0,0 +1,248 @@
+/// Module that selects the days a multi-day command runs, e.g. `cargo all 1-10 --unsolved`.
+use std::{collections::HashSet, path::Path, process::Command};
+
+use crate::template::answers::Answers;
+use crate::template::timings::Timings;
+use crate::template::{all_days, find_solution, Day, Solution, Year};
+
+/// The days to run. Days given as a list start the selection, which defaults to every day.
+/// `unsolved` and `changed_since` narrow it down, and `slowest` keeps the slowest of the remaining days.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DaySelector {
+    /// Days and ranges of days, e.g. `1-10,12`, see [`parse_days`].
+    pub days: Option<HashSet<Day>>,
+    /// Only days with a part that has no stored answer.
+    pub unsolved: bool,
+    /// Only days whose solution or data changed since a git revision, e.g. `main` or `HEAD~3`.
+    /// Changes to the shared code in `src/` affect every day.
+    pub changed_since: Option<String>,
+    /// Only the `n` days with the slowest stored timings.
+    pub slowest: Option<usize>,
+}
+
+impl DaySelector {
+    /// Whether any days were selected. Commands fall back to their own default otherwise.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Resolve the selection to a set of days.
+    pub fn select(&self, solutions: &[Solution], year: Year) -> Result<HashSet<Day>, String> {
+        let mut days: HashSet<Day> = self.days.clone().unwrap_or_else(|| all_days().collect());
+
+        if self.unsolved {
+            days.retain(|&day| is_unsolved(solutions, year, day));
+        }
+
+        if let Some(revision) = &self.changed_since {
+            let changed = changed_days(year, revision)?;
+            days.retain(|day| changed.contains(day));
+        }
+
+        if let Some(n) = self.slowest {
+            days = slowest_days(&Timings::read_from_file(year), &days, n);
+        }
+
+        Ok(days)
+    }
+}
+
+/// Parse a list of days and ranges of days, e.g. `3,7,12` or `1-10`.
+pub fn parse_days(s: &str) -> Result<HashSet<Day>, String> {
+    let parse_day = |day: &str| {
+        day.trim()
+            .parse::<Day>()
+            .map_err(|e| format!("invalid day `{}`: {e}.", day.trim()))
+    };
+
+    let mut days = HashSet::new();
+
+    for item in s.split(',') {
+        match item.split_once('-') {
+            Some((start, end)) => {
+                let (start, end) = (parse_day(start)?, parse_day(end)?);
+                if start > end {
+                    return Err(format!("invalid range `{item}`, it ends before it starts."));
+                }
+                days.extend(all_days().filter(|day| (start..=end).contains(day)));
+            }
+            None => {
+                days.insert(parse_day(item)?);
+            }
+        }
+    }
+
+    Ok(days)
+}
+
+/// Whether a day has a part without a stored answer. Days that have not been scaffolded are unsolved.
+fn is_unsolved(solutions: &[Solution], year: Year, day: Day) -> bool {
+    let answers = Answers::read_from_file(year, day);
+    let parts = find_solution(solutions, year, day).map_or(&[1, 2][..], |solution| solution.parts);
+
+    parts.iter().any(|&part| answers.get(part).is_none())
+}
+
+/// The `n` days with the highest total time. Days without stored timings are skipped.
+fn slowest_days(timings: &Timings, days: &HashSet<Day>, n: usize) -> HashSet<Day> {
+    let mut timed: Vec<_> = timings
+        .data
+        .iter()
+        .filter(|timing| days.contains(&timing.day))
+        .collect();
+
+    timed.sort_unstable_by(|a, b| b.total_nanos.total_cmp(&a.total_nanos));
+    timed.iter().take(n).map(|timing| timing.day).collect()
+}
+
+/// The days with changes since a git revision, including uncommitted and untracked files.
+/// Paths are relative to the working directory like `data_dir`, which need not be the root of the repository.
+fn changed_days(year: Year, revision: &str) -> Result<HashSet<Day>, String> {
+    let git = |args: &[&str]| {
+        let output = Command::new("git")
+            .args(args)
+            .output()
+            .map_err(|e| format!("failed to run git: {e}"))?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "git {} failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    let changed = git(&["diff", "--name-only", "--relative", revision, "--"])?;
+    let untracked = git(&["ls-files", "--others", "--exclude-standard"])?;
+
+    let mut days = HashSet::new();
+
+    for path in changed.lines().chain(untracked.lines()) {
+        match day_of_path(year, path) {
+            Some(day) => {
+                days.insert(day);
+            }
+            None if is_shared_code(path) => return Ok(all_days().collect()),
+            None => {}
+        }
+    }
+
+    Ok(days)
+}
+
+/// The day a file belongs to, e.g. `src/bin/2024-06.rs`, `data/2024/inputs/06.txt` or `data/2024/inputs/06/alt.txt`.
+fn day_of_path(year: Year, path: &str) -> Option<Day> {
+    let path = Path::new(path);
+
+    if let Ok(bin) = path.strip_prefix("src/bin") {
+        let stem = bin.file_stem()?.to_str()?;
+        let (bin_year, day) = stem.split_once('-')?;
+        return (bin_year == year.to_string()).then(|| day.parse().ok())?;
+    }
+
+    let data = path
+        .strip_prefix(Path::new("data").join(year.to_string()))
+        .ok()?;
+    // the first component is the folder, e.g. `inputs`, the second the day or its file.
+    let file = data.components().nth(1)?.as_os_str().to_str()?;
+    let day = file.split(['.', '-']).next()?;
+
+    day.parse().ok()
+}
+
+/// Whether a file is shared by every solution, e.g. the template or helpers in `src/`, or the build setup.
+fn is_shared_code(path: &str) -> bool {
+    matches!(path, "Cargo.toml" | "Cargo.lock" | "build.rs")
+        || (path.starts_with("src/") && !path.starts_with("src/bin/"))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{day_of_path, is_shared_code, parse_days, slowest_days};
+    use crate::{
+        day,
+        template::{
+            all_days,
+            timings::{Timing, Timings},
+        },
+        year,
+    };
+
+    #[test]
+    fn parses_days() {
+        assert_eq!(
+            parse_days("3,7,12"),
+            Ok(HashSet::from([day!(3), day!(7), day!(12)]))
+        );
+        assert_eq!(parse_days("1-10").unwrap().len(), 10);
+        assert_eq!(parse_days("1-3, 25").unwrap().len(), 4);
+        assert!(parse_days("10-1").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("1,,2").is_err());
+    }
+
+    #[test]
+    fn finds_days_of_paths() {
+        let year = year!(2024);
+        assert_eq!(day_of_path(year, "src/bin/2024-06.rs"), Some(day!(6)));
+        assert_eq!(day_of_path(year, "src/bin/2023-06.rs"), None);
+        assert_eq!(day_of_path(year, "data/2024/inputs/14.txt"), Some(day!(14)));
+        assert_eq!(
+            day_of_path(year, "data/2024/examples/17-2.txt"),
+            Some(day!(17))
+        );
+        assert_eq!(
+            day_of_path(year, "data/2024/inputs/06/alt.txt"),
+            Some(day!(6))
+        );
+        assert_eq!(day_of_path(year, "data/2024/timings.json"), None);
+        assert_eq!(day_of_path(year, "src/template/runner.rs"), None);
+    }
+
+    #[test]
+    fn finds_shared_code() {
+        assert!(is_shared_code("src/template/runner.rs"));
+        assert!(is_shared_code("Cargo.toml"));
+        assert!(is_shared_code("Cargo.lock"));
+        assert!(is_shared_code("build.rs"));
+        assert!(!is_shared_code("src/bin/2024-06.rs"));
+        assert!(!is_shared_code("data/2024/inputs/06.txt"));
+        assert!(!is_shared_code("README.md"));
+    }
+
+    #[test]
+    fn selects_slowest_days() {
+        let timing = |day, total_nanos| Timing {
+            day,
+            parse: None,
+            part_1: None,
+            part_2: None,
+            total_nanos,
+            exceeded: None,
+        };
+        let timings = Timings {
+            data: vec![
+                timing(day!(1), 5.0),
+                timing(day!(2), 50.0),
+                timing(day!(3), 20.0),
+            ],
+        };
+
+        let all = all_days().collect();
+        assert_eq!(
+            slowest_days(&timings, &all, 2),
+            HashSet::from([day!(2), day!(3)])
+        );
+        assert_eq!(
+            slowest_days(&timings, &HashSet::from([day!(1), day!(3)]), 1),
+            HashSet::from([day!(3)])
+        );
+    }
+}
@@ -0,0 +1,195 @@
+/// Summary statistics over the samples taken while benching a solution part.
+use std::{collections::HashMap, fmt::Display, time::Duration};
+
+use tinyjson::JsonValue;
+
+/// Samples further than `OUTLIER_FENCE` interquartile ranges outside of the quartiles are rejected as outliers.
+const OUTLIER_FENCE: f64 = 1.5;
+
+/// Statistics of a benched solution part. All durations are computed after rejecting outliers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub median: Duration,
+    pub min: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+    /// Number of samples taken, including outliers.
+    pub samples: u128,
+    /// Number of samples that were rejected as outliers.
+    pub outliers: u128,
+}
+
+impl Stats {
+    /// Statistics of a part that was run exactly once.
+    pub fn single(duration: Duration) -> Self {
+        Self {
+            median: duration,
+            min: duration,
+            p95: duration,
+            stddev: Duration::ZERO,
+            samples: 1,
+            outliers: 0,
+        }
+    }
+
+    /// Compute statistics from a non-empty set of samples.
+    /// Outliers are detected with Tukey's fences and excluded from every value but `samples`.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        sorted.sort_unstable_by(f64::total_cmp);
+
+        let q1 = percentile(&sorted, 25.0);
+        let q3 = percentile(&sorted, 75.0);
+        let iqr = q3 - q1;
+        let (lower, upper) = (q1 - OUTLIER_FENCE * iqr, q3 + OUTLIER_FENCE * iqr);
+
+        let inliers: Vec<f64> = sorted
+            .iter()
+            .copied()
+            .filter(|&x| x >= lower && x <= upper)
+            .collect();
+
+        let mean = inliers.iter().sum::<f64>() / inliers.len() as f64;
+        let variance = if inliers.len() > 1 {
+            inliers.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (inliers.len() - 1) as f64
+        } else {
+            0.0
+        };
+
+        Self {
+            median: nanos(percentile(&inliers, 50.0)),
+            min: nanos(inliers[0]),
+            p95: nanos(percentile(&inliers, 95.0)),
+            stddev: nanos(variance.sqrt()),
+            samples: sorted.len() as u128,
+            outliers: (sorted.len() - inliers.len()) as u128,
+        }
+    }
+}
+
+/// Linearly interpolated percentile of sorted, non-empty values.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = p / 100.0 * (sorted.len() - 1) as f64;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let (lo, hi) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[lo] + (sorted[hi] - sorted[lo]) * (rank - lo as f64)
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn nanos(value: f64) -> Duration {
+    Duration::from_nanos(value.round() as u64)
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.samples > 1 {
+            write!(f, "{:.1?} ± {:.1?}", self.median, self.stddev)
+        } else {
+            write!(f, "{:.1?}", self.median)
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Stats> for HashMap<String, JsonValue> {
+    fn from(value: &Stats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        let nanos = |d: Duration| JsonValue::Number(d.as_nanos() as f64);
+
+        map.insert("median_nanos".into(), nanos(value.median));
+        map.insert("min_nanos".into(), nanos(value.min));
+        map.insert("p95_nanos".into(), nanos(value.p95));
+        map.insert("stddev_nanos".into(), nanos(value.stddev));
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+
+        map
+    }
+}
+
+impl From<&Stats> for JsonValue {
+    fn from(value: &Stats) -> Self {
+        JsonValue::Object(value.into())
+    }
+}
+
+impl TryFrom<&HashMap<String, JsonValue>> for Stats {
+    type Error = String;
+
+    fn try_from(json: &HashMap<String, JsonValue>) -> Result<Self, Self::Error> {
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected stats.{key} to be a number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(Stats {
+            median: nanos(number("median_nanos")?),
+            min: nanos(number("min_nanos")?),
+            p95: nanos(number("p95_nanos")?),
+            stddev: nanos(number("stddev_nanos")?),
+            samples: number("samples")? as u128,
+            outliers: number("outliers")? as u128,
+        })
+    }
+}
+
+impl TryFrom<&JsonValue> for Stats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected stats to be a JSON object.".to_string())
+            .and_then(Stats::try_from)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::Stats;
+
+    fn micros(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_micros).collect()
+    }
+
+    #[test]
+    fn computes_stats() {
+        let stats = Stats::from_samples(&micros(&[5, 1, 4, 2, 3]));
+        assert_eq!(stats.median, Duration::from_micros(3));
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.p95, Duration::from_nanos(4800));
+        assert_eq!(stats.stddev, Duration::from_nanos(1581));
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let stats = Stats::from_samples(&micros(&[10, 11, 10, 12, 11, 10, 500]));
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.samples, 7);
+        assert_eq!(stats.median, Duration::from_nanos(10500));
+        assert!(stats.p95 < Duration::from_micros(13));
+    }
+
+    #[test]
+    fn handles_single_sample() {
+        let stats = Stats::from_samples(&micros(&[7]));
+        assert_eq!(stats, Stats::single(Duration::from_micros(7)));
+    }
+
+    #[test]
+    fn formats_stats() {
+        let stats = Stats::from_samples(&micros(&[5, 1, 4, 2, 3]));
+        assert_eq!(stats.to_string(), "3.0µs ± 1.6µs");
+        assert_eq!(Stats::single(Duration::from_millis(2)).to_string(), "2.0ms");
+    }
+}
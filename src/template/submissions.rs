@@ -0,0 +1,391 @@
+/// Module that keeps a local log of submitted answers and the verdicts they received.
+/// The log guards against re-submitting answers that are already known to be wrong.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::Error,
+    path::PathBuf,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::{data_dir, Day, Year};
+
+/// The verdict of a submission, as reported by Advent of Code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Wrong,
+    RateLimited,
+    /// The verdict could not be determined, e.g. because the part was already completed.
+    Unknown,
+}
+
+impl Verdict {
+    /// Parse the verdict from the response to a submission.
+    pub fn from_output(output: &str) -> Self {
+        if output.contains("That's the right answer") {
+            Verdict::Correct
+        } else if output.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if output.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if output.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else if output.contains("You gave an answer too recently") {
+            Verdict::RateLimited
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    fn is_wrong(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+/// A single submitted answer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Submission {
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+}
+
+/// The submissions of a single day, oldest first.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Submissions {
+    pub part_1: Vec<Submission>,
+    pub part_2: Vec<Submission>,
+}
+
+impl Submissions {
+    /// Rehydrate the submission log of a day. If not present, returns an empty log.
+    pub fn read_from_file(year: Year, day: Day) -> Self {
+        match fs::read_to_string(get_path(year, day)) {
+            Ok(s) => Submissions::try_from(s).unwrap_or_else(|e| {
+                eprintln!("Ignoring submission log for day {day}: {e}");
+                Submissions::default()
+            }),
+            Err(_) => Submissions::default(),
+        }
+    }
+
+    /// Dehydrate the submission log of a day to a JSON file.
+    pub fn store_file(&self, year: Year, day: Day) -> Result<(), Error> {
+        fs::create_dir_all(get_dir(year))?;
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(get_path(year, day))?;
+        json.format_to(&mut file)
+    }
+
+    /// The submissions of a part. Fails for parts other than 1 and 2.
+    pub fn get(&self, part: u8) -> Result<&[Submission], String> {
+        match part {
+            1 => Ok(&self.part_1),
+            2 => Ok(&self.part_2),
+            _ => Err(format!("part {part} does not exist.")),
+        }
+    }
+
+    /// Record a submission that was just made. Fails for parts other than 1 and 2.
+    pub fn record(&mut self, part: u8, answer: &str, verdict: Verdict) -> Result<(), String> {
+        let submissions = match part {
+            1 => &mut self.part_1,
+            2 => &mut self.part_2,
+            _ => return Err(format!("part {part} does not exist.")),
+        };
+
+        submissions.push(Submission {
+            answer: answer.to_string(),
+            verdict,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+        });
+
+        Ok(())
+    }
+
+    /// Check whether an answer is worth submitting. Returns the reason if it is not.
+    pub fn check(&self, part: u8, answer: &str) -> Result<(), String> {
+        let submissions = self.get(part)?;
+
+        if submissions.iter().any(|s| s.verdict == Verdict::Correct) {
+            return Err(format!("part {part} was already solved."));
+        }
+
+        if answer.contains('\n') {
+            return Err(
+                "the answer spans multiple lines, e.g. a grid that could not be read as letters."
+                    .into(),
+            );
+        }
+
+        if let Some(s) = submissions
+            .iter()
+            .find(|s| s.answer == answer && s.verdict.is_wrong())
+        {
+            return Err(format!(
+                "\"{answer}\" was already submitted and was {}.",
+                s.verdict
+            ));
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+
+        let bound = |verdict: Verdict| {
+            submissions
+                .iter()
+                .filter(move |s| s.verdict == verdict)
+                .filter_map(|s| s.answer.parse::<i128>().ok())
+        };
+
+        if let Some(too_high) = bound(Verdict::TooHigh).min().filter(|&b| value >= b) {
+            return Err(format!(
+                "{value} is not below {too_high}, which was too high."
+            ));
+        }
+
+        if let Some(too_low) = bound(Verdict::TooLow).max().filter(|&b| value <= b) {
+            return Err(format!(
+                "{value} is not above {too_low}, which was too low."
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+fn get_dir(year: Year) -> PathBuf {
+    data_dir(year).join("submissions")
+}
+
+fn get_path(year: Year, day: Day) -> PathBuf {
+    get_dir(year).join(format!("{day}.json"))
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+            Verdict::RateLimited => "rate-limited",
+            Verdict::Unknown => "unknown",
+        })
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too high" => Ok(Verdict::TooHigh),
+            "too low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            "rate-limited" => Ok(Verdict::RateLimited),
+            "unknown" => Ok(Verdict::Unknown),
+            _ => Err(format!("unknown verdict `{s}`.")),
+        }
+    }
+}
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "verdict".into(),
+            JsonValue::String(value.verdict.to_string()),
+        );
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        let verdict = json
+            .get("verdict")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.verdict to be a string.")?
+            .parse()?;
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected submission.timestamp to be a number.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(Submission {
+            answer: answer.clone(),
+            verdict,
+            timestamp: timestamp as u64,
+        })
+    }
+}
+
+impl From<&Submissions> for JsonValue {
+    fn from(value: &Submissions) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        let list = |submissions: &[Submission]| {
+            JsonValue::Array(submissions.iter().map(JsonValue::from).collect())
+        };
+
+        map.insert("part_1".into(), list(&value.part_1));
+        map.insert("part_2".into(), list(&value.part_2));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Submissions {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let list = |key: &str| -> Result<Vec<Submission>, String> {
+            match json.get(key) {
+                None => Ok(vec![]),
+                Some(v) => v
+                    .get::<Vec<JsonValue>>()
+                    .ok_or(format!("expected `{key}` to be an array."))?
+                    .iter()
+                    .map(Submission::try_from)
+                    .collect(),
+            }
+        };
+
+        Ok(Submissions {
+            part_1: list("part_1")?,
+            part_2: list("part_2")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{Submissions, Verdict};
+
+    fn log(part_1: &[(&str, Verdict)]) -> Submissions {
+        let mut submissions = Submissions::default();
+        for (answer, verdict) in part_1 {
+            submissions.record(1, answer, *verdict).unwrap();
+        }
+        submissions
+    }
+
+    #[test]
+    fn parses_verdicts() {
+        assert_eq!(
+            Verdict::from_output("That's the right answer! You are one gold star closer."),
+            Verdict::Correct
+        );
+        assert_eq!(
+            Verdict::from_output("That's not the right answer; your answer is too high."),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            Verdict::from_output("That's not the right answer; your answer is too low."),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            Verdict::from_output("That's not the right answer. If you're stuck, ..."),
+            Verdict::Wrong
+        );
+        assert_eq!(
+            Verdict::from_output("You gave an answer too recently; you have 30s left to wait."),
+            Verdict::RateLimited
+        );
+        assert_eq!(
+            Verdict::from_output("You don't seem to be solving the right level."),
+            Verdict::Unknown
+        );
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let submissions = log(&[("abc", Verdict::Wrong), ("def", Verdict::RateLimited)]);
+        assert!(submissions.check(1, "abc").is_err());
+        assert!(submissions.check(1, "def").is_ok());
+        assert!(submissions.check(2, "abc").is_ok());
+    }
+
+    #[test]
+    fn refuses_answers_outside_of_bounds() {
+        let submissions = log(&[("100", Verdict::TooHigh), ("10", Verdict::TooLow)]);
+        assert!(submissions.check(1, "100").is_err());
+        assert!(submissions.check(1, "150").is_err());
+        assert!(submissions.check(1, "10").is_err());
+        assert!(submissions.check(1, "-5").is_err());
+        assert!(submissions.check(1, "50").is_ok());
+    }
+
+    #[test]
+    fn rejects_unknown_parts() {
+        let mut submissions = Submissions::default();
+        assert!(submissions.record(3, "42", Verdict::Wrong).is_err());
+        assert!(submissions.check(3, "42").is_err());
+        assert_eq!(submissions, Submissions::default());
+    }
+
+    #[test]
+    fn refuses_multi_line_answers() {
+        let submissions = Submissions::default();
+        assert!(submissions.check(1, "#..#\n####").is_err());
+    }
+
+    #[test]
+    fn refuses_solved_parts() {
+        let submissions = log(&[("42", Verdict::Correct)]);
+        assert!(submissions.check(1, "43").is_err());
+    }
+
+    #[test]
+    fn round_trips_submissions() {
+        let submissions = log(&[("100", Verdict::TooHigh), ("10", Verdict::TooLow)]);
+        let json = JsonValue::from(&submissions).stringify().unwrap();
+        assert_eq!(Submissions::try_from(json).unwrap(), submissions);
+    }
+}
@@ -0,0 +1,250 @@
+/// Module that summarizes the state of every part of a year in a grid, see [`Summary`].
+use std::collections::HashSet;
+
+use crate::template::answers::{Answers, Check};
+use crate::template::runner::{PartResult, Status, PARSE_PART};
+use crate::template::{all_days, find_solution, Day, Solution, Year, ANSI_BOLD, ANSI_RESET};
+
+/// The state of a single part in a [`Summary`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PartState {
+    /// The answer matches the stored answer, which earns a star.
+    Correct,
+    /// The part has an answer, but there is no stored answer to check it against.
+    Solved,
+    Wrong,
+    Unsolved,
+    /// The part panicked, its input could not be parsed or it exceeded a limit.
+    Panicked,
+    /// The part did not run, e.g. because the day has not been scaffolded yet.
+    Missing,
+}
+
+impl PartState {
+    fn from_result(result: &PartResult) -> Self {
+        match (result.status, result.check) {
+            (Status::Solved, Check::Correct) => PartState::Correct,
+            (Status::Solved, Check::Wrong) => PartState::Wrong,
+            (Status::Solved, Check::Unknown) => PartState::Solved,
+            (Status::Unsolved, _) => PartState::Unsolved,
+            (Status::Panicked | Status::Invalid | Status::Exceeded(_), _) => PartState::Panicked,
+        }
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            PartState::Correct => "★",
+            PartState::Solved => "✔",
+            PartState::Wrong => "✘",
+            PartState::Unsolved => "✖",
+            PartState::Panicked => "!",
+            PartState::Missing => "·",
+        }
+    }
+}
+
+/// The state of both parts of all 25 days of a year.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Summary {
+    parts: [[PartState; 2]; 25],
+}
+
+impl Summary {
+    /// Summarize the results of a run. Days without results are missing.
+    pub fn from_results(results: &[PartResult]) -> Self {
+        let mut parts = [[PartState::Missing; 2]; 25];
+
+        for result in results.iter().filter(|result| result.part != PARSE_PART) {
+            if let Some(state) = parts[index(result.day)].get_mut(usize::from(result.part) - 1) {
+                *state = PartState::from_result(result);
+            }
+        }
+
+        Self { parts }
+    }
+
+    /// Summarize the stored answers of a year without running anything.
+    /// Parts of scaffolded days without a stored answer are unsolved.
+    pub fn from_answers(solutions: &[Solution], year: Year) -> Self {
+        Self::from_stored(solutions, year, |day| Answers::read_from_file(year, day))
+    }
+
+    fn from_stored(solutions: &[Solution], year: Year, read: impl Fn(Day) -> Answers) -> Self {
+        let mut parts = [[PartState::Missing; 2]; 25];
+
+        for day in all_days() {
+            let answers = read(day);
+            let solution = find_solution(solutions, year, day);
+
+            for part in [1, 2] {
+                parts[index(day)][usize::from(part) - 1] = match answers.get(part) {
+                    Some(_) => PartState::Correct,
+                    None if solution.is_some_and(|s| s.parts.contains(&part)) => {
+                        PartState::Unsolved
+                    }
+                    None => PartState::Missing,
+                };
+            }
+        }
+
+        Self { parts }
+    }
+
+    /// Leave out the days that are not in `days`, e.g. the days that were not selected, by marking them as missing.
+    pub fn only(mut self, days: &HashSet<Day>) -> Self {
+        for day in all_days().filter(|day| !days.contains(day)) {
+            self.parts[index(day)] = [PartState::Missing; 2];
+        }
+        self
+    }
+
+    /// The number of stars, i.e. parts with a correct answer.
+    pub fn stars(&self) -> usize {
+        self.count(PartState::Correct)
+    }
+
+    /// The number of parts in a state.
+    pub fn count(&self, state: PartState) -> usize {
+        self.parts.iter().flatten().filter(|&&s| s == state).count()
+    }
+
+    /// The grid of days and parts, followed by a legend.
+    fn grid(&self) -> Vec<String> {
+        let days: String = all_days()
+            .map(|day| format!("{:>3}", day.into_inner()))
+            .collect();
+        let mut lines = vec![format!("Day   {days}")];
+
+        for part in 0..2 {
+            let states: String = self
+                .parts
+                .iter()
+                .map(|day| format!("{:>3}", day[part].symbol()))
+                .collect();
+            lines.push(format!("Part {}{states}", part + 1));
+        }
+
+        lines.push(String::new());
+        lines.push(format!(
+            "★ {}  ✔ {}  ✘ {}  ✖ {}  ! {}  (correct, solved, wrong, unsolved, panicked)",
+            self.count(PartState::Correct),
+            self.count(PartState::Solved),
+            self.count(PartState::Wrong),
+            self.count(PartState::Unsolved),
+            self.count(PartState::Panicked),
+        ));
+
+        lines
+    }
+}
+
+fn index(day: Day) -> usize {
+    usize::from(day.into_inner()) - 1
+}
+
+/// Print the summary grid and the total of stars.
+pub fn print_summary(summary: &Summary) {
+    println!("{ANSI_BOLD}Summary{ANSI_RESET}");
+    println!("------");
+
+    for line in summary.grid() {
+        println!("{line}");
+    }
+
+    println!("\n{ANSI_BOLD}Stars:{ANSI_RESET} {} / 50", summary.stars());
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{collections::HashSet, time::Duration};
+
+    use super::{PartState, Summary};
+    use crate::template::{
+        answers::{Answers, Check},
+        runner::{PartResult, Status},
+        stats::Stats,
+        Day, Solution,
+    };
+    use crate::{day, year};
+
+    fn result(day: u8, part: u8, status: Status, check: Check) -> PartResult {
+        PartResult {
+            day: Day::new(day).unwrap(),
+            part,
+            answer: (status == Status::Solved).then(|| "42".into()),
+            stats: Stats::single(Duration::ZERO),
+            status,
+            check,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn summarizes_results() {
+        let summary = Summary::from_results(&[
+            result(1, 0, Status::Solved, Check::Unknown),
+            result(1, 1, Status::Solved, Check::Correct),
+            result(1, 2, Status::Solved, Check::Wrong),
+            result(2, 1, Status::Solved, Check::Unknown),
+            result(2, 2, Status::Panicked, Check::Unknown),
+            result(25, 1, Status::Unsolved, Check::Wrong),
+        ]);
+
+        assert_eq!(summary.stars(), 1);
+        assert_eq!(summary.count(PartState::Wrong), 1);
+        assert_eq!(summary.count(PartState::Missing), 45);
+
+        let grid = summary.grid();
+        assert!(grid[0].starts_with("Day     1  2  3"));
+        assert!(grid[1].starts_with("Part 1  ★  ✔  ·"));
+        assert!(grid[2].starts_with("Part 2  ✘  !  ·"));
+        assert!(grid[1].ends_with("·  ✖"));
+    }
+
+    #[test]
+    fn summarizes_answers() {
+        let solution = |day| Solution {
+            year: year!(2024),
+            day,
+            parts: &[1, 2],
+            has_parse: false,
+            run: |_, _, _| vec![],
+        };
+        let solutions = [solution(day!(1)), solution(day!(2))];
+
+        let summary = Summary::from_stored(&solutions, year!(2024), |day| {
+            let mut answers = Answers::default();
+            if day == day!(1) {
+                answers.set(1, "42");
+                answers.set(2, "43");
+            } else if day == day!(3) {
+                answers.set(1, "44");
+            }
+            answers
+        });
+
+        assert_eq!(summary.stars(), 3);
+        assert_eq!(summary.count(PartState::Unsolved), 2);
+        assert_eq!(summary.count(PartState::Missing), 45);
+
+        let grid = summary.grid();
+        assert!(grid[1].starts_with("Part 1  ★  ✖  ★  ·"));
+        assert!(grid[2].starts_with("Part 2  ★  ✖  ·  ·"));
+    }
+
+    #[test]
+    fn leaves_out_unselected_days() {
+        let summary = Summary::from_results(&[
+            result(1, 1, Status::Solved, Check::Correct),
+            result(2, 1, Status::Solved, Check::Correct),
+            result(2, 2, Status::Solved, Check::Correct),
+        ])
+        .only(&HashSet::from([day!(2), day!(3)]));
+
+        assert_eq!(summary.stars(), 2);
+        assert_eq!(summary.count(PartState::Missing), 48);
+        assert!(summary.grid()[1].starts_with("Part 1  ·  ★  ·"));
+    }
+}
@@ -1,17 +1,53 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{collections::HashMap, fs, io::Error, path::PathBuf, str::FromStr, time::Duration};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
-
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+use crate::template::limits::Limit;
+use crate::template::runner::{PartResult, Status, PARSE_PART};
+use crate::template::stats::Stats;
+use crate::template::{data_dir, Day, Year};
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    /// The time of the parse step, for solutions that have one.
+    pub parse: Option<Stats>,
+    pub part_1: Option<Stats>,
+    pub part_2: Option<Stats>,
     pub total_nanos: f64,
+    /// The part that was killed for exceeding a limit, if any. Later parts of the day did not run.
+    pub exceeded: Option<(u8, Limit)>,
+}
+
+impl Timing {
+    /// Collect the timings of the solved parts of a day.
+    pub fn from_results(day: Day, results: &[PartResult]) -> Self {
+        let solved = || {
+            results
+                .iter()
+                .filter(|result| result.day == day && result.status == Status::Solved)
+        };
+
+        let part_timing = |part: u8| {
+            solved()
+                .find(|result| result.part == part)
+                .map(|result| result.stats)
+        };
+
+        Timing {
+            day,
+            parse: part_timing(PARSE_PART),
+            part_1: part_timing(1),
+            part_2: part_timing(2),
+            total_nanos: solved()
+                .map(|result| result.stats.median.as_nanos() as f64)
+                .sum(),
+            exceeded: results.iter().find_map(|result| match result.status {
+                Status::Exceeded(limit) if result.day == day => Some((result.part, limit)),
+                _ => None,
+            }),
+        }
+    }
 }
 
 /// Represents benchmark times for a set of days.
@@ -23,15 +59,16 @@ pub struct Timings {
 
 impl Timings {
     /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        fs::create_dir_all(data_dir(year))?;
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = fs::File::create(get_path(year))?;
         json.format_to(&mut file)
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        let s = fs::read_to_string(TIMINGS_FILE_PATH)
+    pub fn read_from_file(year: Year) -> Self {
+        let s = fs::read_to_string(get_path(year))
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from);
 
@@ -74,6 +111,10 @@ impl Timings {
     }
 }
 
+fn get_path(year: Year) -> PathBuf {
+    data_dir(year).join("timings.json")
+}
+
 /* -------------------------------------------------------------------------- */
 
 impl From<Timings> for JsonValue {
@@ -94,7 +135,14 @@ impl TryFrom<String> for Timings {
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+        Timings::try_from(&json)
+    }
+}
+
+impl TryFrom<&JsonValue> for Timings {
+    type Error = String;
 
+    fn try_from(json: &JsonValue) -> Result<Self, Self::Error> {
         let json_data = json
             .get::<HashMap<String, JsonValue>>()
             .ok_or("expected JSON document to be an object.")?
@@ -121,8 +169,20 @@ impl From<&Timing> for JsonValue {
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
 
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
+        if let Some(parse) = &value.parse {
+            map.insert("parse".into(), JsonValue::from(parse));
+        }
+
+        if let Some((part, limit)) = value.exceeded {
+            let exceeded = HashMap::from([
+                ("part".to_string(), JsonValue::Number(f64::from(part))),
+                ("limit".to_string(), JsonValue::String(limit.to_string())),
+            ]);
+            map.insert("exceeded".into(), JsonValue::Object(exceeded));
+        }
+
+        let part_1 = value.part_1.as_ref().map(JsonValue::from);
+        let part_2 = value.part_2.as_ref().map(JsonValue::from);
 
         map.insert(
             "part_1".into(),
@@ -158,78 +218,156 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|day| Day::from_str(day).ok())
             .ok_or("Expected timing.day to be a Day struct.")?;
 
+        // timings stored before parse steps were introduced have no parse time.
+        let parse = json
+            .get("parse")
+            .filter(|v| !v.is_null())
+            .map(Stats::try_from)
+            .transpose()?;
+
         let part_1 = json
             .get("part_1")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_1 to be null or string.")?;
+            .map(|v| (!v.is_null()).then(|| part_stats(v)).transpose())
+            .ok_or("Expected timing.part_1 to be null or stats.")??;
 
         let part_2 = json
             .get("part_2")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_2 to be null or string.")?;
+            .map(|v| (!v.is_null()).then(|| part_stats(v)).transpose())
+            .ok_or("Expected timing.part_2 to be null or stats.")??;
 
         let total_nanos = json
             .get("total_nanos")
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        let exceeded = json
+            .get("exceeded")
+            .filter(|v| !v.is_null())
+            .map(|v| {
+                let exceeded = v.get::<HashMap<String, JsonValue>>()?;
+                let part = exceeded.get("part")?.get::<f64>()?;
+                let limit = exceeded.get("limit")?.get::<String>()?.parse().ok()?;
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                Some((*part as u8, limit))
+            })
+            .map(|exceeded| exceeded.ok_or("Expected timing.exceeded to have a part and limit."))
+            .transpose()?;
+
         Ok(Timing {
             day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
+            parse,
+            part_1,
+            part_2,
             total_nanos,
+            exceeded,
         })
     }
 }
 
+/// Read the stats of a part. Timings stored before stats were introduced hold a formatted duration
+/// like `"74.13ns"` instead, which is read as a single sample.
+fn part_stats(value: &JsonValue) -> Result<Stats, String> {
+    match value.get::<String>() {
+        Some(duration) => parse_duration(duration)
+            .map(Stats::single)
+            .ok_or(format!("Expected `{duration}` to be a duration.")),
+        None => Stats::try_from(value),
+    }
+}
+
+/// Parse a duration in the `Debug` format of [`Duration`], e.g. `"74.13ns"` or `"1.2ms"`.
+fn parse_duration(s: &str) -> Option<Duration> {
+    let units = [("ns", 1.0), ("µs", 1e3), ("ms", 1e6), ("s", 1e9)];
+    let (value, unit_nanos) = units
+        .iter()
+        .find_map(|&(unit, unit_nanos)| Some((s.strip_suffix(unit)?, unit_nanos)))?;
+    let nanos = value.parse::<f64>().ok()? * unit_nanos;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    (nanos >= 0.0).then(|| Duration::from_nanos(nanos.round() as u64))
+}
+
 /* -------------------------------------------------------------------------- */
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use crate::day;
+    use std::time::Duration;
+
+    use crate::{day, template::stats::Stats};
 
     use super::{Timing, Timings};
 
+    fn ms(millis: u64) -> Stats {
+        Stats::single(Duration::from_millis(millis))
+    }
+
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    parse: None,
+                    part_1: Some(ms(10)),
+                    part_2: Some(ms(20)),
                     total_nanos: 3e+10,
+                    exceeded: None,
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    parse: None,
+                    part_1: Some(ms(30)),
+                    part_2: Some(ms(40)),
                     total_nanos: 7e+10,
+                    exceeded: None,
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
+                    parse: None,
+                    part_1: Some(ms(40)),
                     part_2: None,
                     total_nanos: 4e+10,
+                    exceeded: None,
                 },
             ],
         }
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use std::time::Duration;
+
+        use super::ms;
+        use crate::{
+            day,
+            template::{limits::Limit, timings::Timings},
+        };
 
         #[test]
         fn handles_json_timings() {
-            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
+            let json = r#"{ "data": [{ "day": "01", "part_1": { "median_nanos": 1000000, "min_nanos": 1000000, "p95_nanos": 1000000, "stddev_nanos": 0, "samples": 1, "outliers": 0 }, "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
             assert_eq!(timing.day, day!(1));
-            assert_eq!(timing.part_1, Some("1ms".to_string()));
+            assert_eq!(timing.part_1, Some(ms(1)));
             assert_eq!(timing.part_2, None);
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_legacy_duration_strings() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "74.13ns", "part_2": "1.5ms", "total_nanos": 1500074.13 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.part_1.unwrap().median, Duration::from_nanos(74));
+            assert_eq!(timing.part_2.unwrap().median, Duration::from_micros(1500));
+        }
+
+        #[test]
+        fn handles_exceeded_limits() {
+            let json = r#"{ "data": [{ "day": "06", "part_1": null, "part_2": null, "total_nanos": 0, "exceeded": { "part": 2, "limit": "OOM" } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].exceeded, Some((2, Limit::Memory)));
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -276,6 +414,7 @@ mod tests {
     }
 
     mod is_day_complete {
+        use super::ms;
         use crate::{
             day,
             template::timings::{Timing, Timings},
@@ -286,13 +425,15 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: Some("2ms".into()),
+                    parse: None,
+                    part_1: Some(ms(1)),
+                    part_2: Some(ms(2)),
                     total_nanos: 3_000_000_000_f64,
+                    exceeded: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -300,13 +441,15 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
+                    parse: None,
+                    part_1: Some(ms(1)),
                     part_2: None,
                     total_nanos: 1_000_000_000_f64,
+                    exceeded: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
@@ -314,13 +457,77 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
+                    parse: None,
                     part_1: None,
                     part_2: None,
                     total_nanos: 0.0,
+                    exceeded: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
+        }
+    }
+
+    mod from_results {
+        use std::time::Duration;
+
+        use crate::{
+            day,
+            template::{
+                answers::Check,
+                limits::Limit,
+                runner::{PartResult, Status},
+                stats::Stats,
+                timings::Timing,
+            },
+        };
+
+        fn result(part: u8, status: Status) -> PartResult {
+            PartResult {
+                day: day!(1),
+                part,
+                answer: (status == Status::Solved).then(|| "42".into()),
+                stats: Stats::single(Duration::from_millis(u64::from(part))),
+                status,
+                check: Check::Unknown,
+                error: None,
+            }
+        }
+
+        #[test]
+        fn collects_solved_parts() {
+            let timing = Timing::from_results(
+                day!(1),
+                &[result(1, Status::Solved), result(2, Status::Solved)],
+            );
+            assert_eq!(timing.part_1.unwrap().median, Duration::from_millis(1));
+            assert_eq!(timing.part_2.unwrap().median, Duration::from_millis(2));
+            assert_eq!(timing.total_nanos, 3_000_000_f64);
+        }
+
+        #[test]
+        fn skips_unsolved_parts() {
+            let timing = Timing::from_results(
+                day!(1),
+                &[result(1, Status::Panicked), result(2, Status::Unsolved)],
+            );
+            assert_eq!(timing.part_1, None);
+            assert_eq!(timing.part_2, None);
+            assert_eq!(timing.total_nanos, 0_f64);
+        }
+
+        #[test]
+        fn records_exceeded_limits() {
+            let timing = Timing::from_results(
+                day!(1),
+                &[
+                    result(1, Status::Solved),
+                    result(2, Status::Exceeded(Limit::Timeout)),
+                ],
+            );
+            assert_eq!(timing.part_2, None);
+            assert_eq!(timing.exceeded, Some((2, Limit::Timeout)));
         }
     }
 
@@ -338,9 +545,11 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(3),
+                    parse: None,
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    exceeded: None,
                 }],
             };
             let merged = timings.merge(&other);
@@ -358,9 +567,11 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(2),
+                    parse: None,
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    exceeded: None,
                 }],
             };
             let merged = timings.merge(&other);
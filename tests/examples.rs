@@ -0,0 +1,13 @@
+//! Checks every example that declares its expected answers against the registered solutions.
+//! See `advent_of_code::template::examples` for the format of example files.
+
+#[cfg(not(feature = "dhat-heap"))]
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+// NOTE: every solution declares its own global allocator when profiling with dhat, so they can't be linked together.
+#[cfg(feature = "dhat-heap")]
+pub const SOLUTIONS: &[advent_of_code::template::Solution] = &[];
+
+fn main() {
+    advent_of_code::template::examples::check_examples(SOLUTIONS);
+}
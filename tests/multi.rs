@@ -0,0 +1,82 @@
+//! Runs the `multi` binary on the examples of a few days, which are used as their inputs.
+#![cfg(all(feature = "test_lib", not(feature = "dhat-heap")))]
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::{self, Command},
+};
+
+use tinyjson::JsonValue;
+
+/// Create an empty data folder for a test, which the `multi` binary is run in.
+fn data_dir(test: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-multi-{test}-{}", process::id()));
+    fs::create_dir_all(dir.join("data/2024/inputs")).unwrap();
+    dir
+}
+
+/// Write the example of a day, without its expected answers, as the input of the day.
+fn write_input(dir: &Path, day: &str) {
+    let example = fs::read_to_string(format!("data/2024/examples/{day}.txt")).unwrap();
+    let (_, input) = example.split_once("---\n").unwrap();
+    fs::write(dir.join(format!("data/2024/inputs/{day}.txt")), input).unwrap();
+}
+
+#[test]
+fn prints_parallel_days_at_once_in_day_order() {
+    let dir = data_dir("parallel");
+    for day in ["01", "14"] {
+        write_input(&dir, day);
+    }
+    // day 2 panics on a level that is not a number, and the message of the panic is output of the day.
+    fs::write(dir.join("data/2024/inputs/02.txt"), "1 2 x\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_multi"))
+        .args(["--year", "2024", "all", "--jobs", "3", "1,2,14"])
+        .current_dir(&dir)
+        .output()
+        .unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+
+    let header = |day: &str| {
+        lines
+            .iter()
+            .position(|line| line.contains(&format!("Day {day}")))
+            .unwrap()
+    };
+    let (day_1, day_2, day_14) = (header("01"), header("02"), header("14"));
+    assert!(day_1 < day_2 && day_2 < day_14);
+
+    let panics: Vec<usize> = (0..lines.len())
+        .filter(|&i| lines[i].contains("panicked at"))
+        .collect();
+    assert!(!panics.is_empty());
+    assert!(panics.iter().all(|&i| day_2 < i && i < day_14));
+}
+
+#[test]
+fn prints_only_the_report_in_other_formats() {
+    let dir = data_dir("json");
+    for day in ["01", "14"] {
+        write_input(&dir, day);
+    }
+    // the panic of day 2 goes to stderr of its child, and is only a result in the report.
+    fs::write(dir.join("data/2024/inputs/02.txt"), "1 2 x\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_multi"))
+        .args(["--year", "2024", "all", "--format", "json", "1,2,14"])
+        .current_dir(&dir)
+        .output()
+        .unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let report: JsonValue = stdout.trim_end().parse().unwrap();
+
+    let results: &Vec<JsonValue> = report["results"].get().unwrap();
+    assert_eq!(results.len(), 6);
+}